@@ -0,0 +1,142 @@
+use bevy::prelude::*;
+use super::components::TileAtmosphere;
+use super::gas::GasType;
+use super::queue::ActiveTileQueue;
+
+/// An occupant that breathes the [`GasMixture`](super::gas::GasMixture) of the
+/// tile it stands on. Each tick it consumes oxygen and exhales an equal amount
+/// of carbon dioxide, then grades the tile's breathability from the oxygen and
+/// carbon-dioxide partial pressures and takes (or recovers) health accordingly.
+///
+/// The last observed partial pressures are stored so the demo's visuals can
+/// highlight unbreathable tiles without recomputing them.
+#[derive(Component, Debug, Clone)]
+pub struct Breather {
+    /// The tile whose atmosphere this occupant exchanges gas with.
+    pub tile: Entity,
+    /// Oxygen consumed per tick, in micro-moles.
+    pub o2_demand: u64,
+    /// Occupant health on a 0–100 scale; zero is dead.
+    pub health: f32,
+    /// Current breathability grade, driving damage and visuals.
+    pub state: BreathState,
+    /// Oxygen partial pressure observed last tick, in μkPa.
+    pub o2_partial: u64,
+    /// Carbon-dioxide partial pressure observed last tick, in μkPa.
+    pub co2_partial: u64,
+}
+
+impl Breather {
+    /// A standard humanoid occupant on `tile`, at full health and consuming the
+    /// documented [`DEFAULT_O2_DEMAND_MICRO_MOLES`] per tick.
+    pub fn new(tile: Entity) -> Self {
+        Self {
+            tile,
+            o2_demand: DEFAULT_O2_DEMAND_MICRO_MOLES,
+            health: 100.0,
+            state: BreathState::Nominal,
+            o2_partial: 0,
+            co2_partial: 0,
+        }
+    }
+}
+
+/// Breathability grade of the air an occupant is currently breathing, worst
+/// hazard wins. Suffocation (too little oxygen) outranks any carbon-dioxide
+/// grade because it is the faster killer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreathState {
+    /// Breathable air: enough oxygen, tolerable carbon dioxide.
+    Nominal,
+    /// Oxygen below [`MIN_O2_PARTIAL_MICRO_KPA`] — the occupant is suffocating.
+    Suffocating,
+    /// Carbon dioxide past [`CO2_UNCOMFORTABLE_MICRO_KPA`].
+    Uncomfortable,
+    /// Carbon dioxide past [`CO2_UNHEALTHY_MICRO_KPA`].
+    Unhealthy,
+    /// Carbon dioxide past [`CO2_DANGEROUS_MICRO_KPA`].
+    Dangerous,
+}
+
+/// Oxygen consumed per tick: ~0.016 mol/s per occupant, in micro-moles.
+pub const DEFAULT_O2_DEMAND_MICRO_MOLES: u64 = 16_000;
+/// Oxygen partial pressure below which an occupant begins suffocating (~16 kPa).
+pub const MIN_O2_PARTIAL_MICRO_KPA: u64 = 16_000_000;
+/// Carbon-dioxide partial pressure that becomes uncomfortable (~1 kPa).
+pub const CO2_UNCOMFORTABLE_MICRO_KPA: u64 = 1_000_000;
+/// Carbon-dioxide partial pressure that is unhealthy (~5 kPa).
+pub const CO2_UNHEALTHY_MICRO_KPA: u64 = 5_000_000;
+/// Carbon-dioxide partial pressure that is acutely dangerous (~10 kPa).
+pub const CO2_DANGEROUS_MICRO_KPA: u64 = 10_000_000;
+
+/// Health lost per tick while suffocating.
+const SUFFOCATION_DAMAGE: f32 = 2.0;
+/// Health lost per tick at each staged carbon-dioxide threshold.
+const CO2_UNCOMFORTABLE_DAMAGE: f32 = 0.1;
+const CO2_UNHEALTHY_DAMAGE: f32 = 0.5;
+const CO2_DANGEROUS_DAMAGE: f32 = 1.5;
+/// Health recovered per tick breathing clean air.
+const RECOVERY_RATE: f32 = 0.5;
+
+/// System to run occupant life support: consume oxygen, exhale carbon dioxide,
+/// and apply breathability-based health changes.
+///
+/// Runs after the gas passes (sharing, chemistry) have settled each tile so the
+/// occupant breathes the air it would actually find there; the occupied tile is
+/// re-queued so scrubbers and diffusion respond to the exhaled CO₂.
+pub fn process_breathing(
+    mut breathers: Query<&mut Breather>,
+    mut tiles: Query<&mut TileAtmosphere>,
+    mut active_queue: ResMut<ActiveTileQueue>,
+) {
+    for mut breather in breathers.iter_mut() {
+        let Ok(mut atmosphere) = tiles.get_mut(breather.tile) else {
+            continue;
+        };
+        let mixture = &mut atmosphere.mixture;
+
+        // Consume oxygen (limited by what's present) and exhale the same number
+        // of moles as carbon dioxide.
+        let consumed = breather.o2_demand.min(mixture.get_moles(GasType::Oxygen));
+        mixture.remove_moles(GasType::Oxygen, consumed);
+        mixture.add_moles(GasType::CarbonDioxide, consumed);
+
+        let o2_pp = mixture.partial_pressure(GasType::Oxygen);
+        let co2_pp = mixture.partial_pressure(GasType::CarbonDioxide);
+        breather.o2_partial = o2_pp;
+        breather.co2_partial = co2_pp;
+
+        // Grade the air: suffocation first, then staged carbon-dioxide toxicity.
+        let mut state = BreathState::Nominal;
+        let mut damage = 0.0;
+        if o2_pp < MIN_O2_PARTIAL_MICRO_KPA {
+            state = BreathState::Suffocating;
+            damage += SUFFOCATION_DAMAGE;
+        }
+        if co2_pp >= CO2_DANGEROUS_MICRO_KPA {
+            damage += CO2_DANGEROUS_DAMAGE;
+            if state != BreathState::Suffocating {
+                state = BreathState::Dangerous;
+            }
+        } else if co2_pp >= CO2_UNHEALTHY_MICRO_KPA {
+            damage += CO2_UNHEALTHY_DAMAGE;
+            if state == BreathState::Nominal {
+                state = BreathState::Unhealthy;
+            }
+        } else if co2_pp >= CO2_UNCOMFORTABLE_MICRO_KPA {
+            damage += CO2_UNCOMFORTABLE_DAMAGE;
+            if state == BreathState::Nominal {
+                state = BreathState::Uncomfortable;
+            }
+        }
+
+        if damage > 0.0 {
+            breather.health = (breather.health - damage).max(0.0);
+        } else {
+            breather.health = (breather.health + RECOVERY_RATE).min(100.0);
+        }
+        breather.state = state;
+
+        active_queue.push(breather.tile);
+    }
+}
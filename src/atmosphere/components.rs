@@ -43,10 +43,16 @@ impl TileAtmosphere {
     }
 }
 
-/// Marker component - presence indicates tile has active gas exchange with neighbors
-/// Tile remains active until equilibrium is reached with all neighbors
-#[derive(Component)]
-pub struct AtmosphereActive;
+/// Per-tile gas flow direction and strength.
+///
+/// `direction` is a unit vector in grid space and `magnitude` is in μkPa of the
+/// dominant pressure gradient (or transfer) driving the flow. Consumed by the
+/// flow-arrow visuals.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct FlowVector {
+    pub direction: Vec2,
+    pub magnitude: f32,
+}
 
 /// Space/void marker
 #[derive(Component)]
@@ -56,6 +62,74 @@ pub struct ExposedToSpace;
 #[derive(Component)]
 pub struct Wall;
 
+/// Partial-barrier marker for a tile — a door or a small gap.
+///
+/// Unlike a [`Wall`], a door tile still exchanges gas with its open neighbors
+/// through the ordinary per-tile [`GasMixture::share_gas_with`] path, so pressure
+/// crosses it slowly. But it does *not* merge the rooms on either side into one
+/// [`super::zones::Zone`]: the zone rebuild keeps them separate and records an
+/// explicit [`super::zones::ZoneEdge`] between them, so a closed door behaves as a
+/// realistic partial barrier rather than collapsing two rooms to one mixture.
+#[derive(Component)]
+pub struct Door;
+
+/// Optional solid-body thermal properties for a tile.
+///
+/// Attached to [`Wall`] tiles (and thermal superconductors) so heat conducts
+/// across a sealed barrier even though no gas crosses it: the barrier stores its
+/// own temperature and heat capacity and slowly equalizes with both sides. Open
+/// tiles conduct through their [`GasMixture`] instead and need no conductor.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ThermalConductor {
+    /// Conductivity as a fraction of the full exchange, in per-mille (0..=1000).
+    pub conductivity: u64,
+    /// Heat capacity in the same scaled J/K units as [`GasMixture::heat_capacity`].
+    pub heat_capacity: u64,
+    /// The body's own temperature in milli-Kelvin.
+    pub temperature: u64,
+}
+
+impl Default for ThermalConductor {
+    fn default() -> Self {
+        // A typical hull wall: low conductivity, a large thermal mass, starting
+        // at room temperature.
+        Self {
+            conductivity: 50,
+            heat_capacity: 5_000,
+            temperature: 293_150,
+        }
+    }
+}
+
+/// Tunables for the atmospheric simulation, configured on the plugin.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct AtmosphereConfig {
+    /// Use the parallel, atomic-accumulator gas-sharing path instead of the
+    /// sequential one. Worth it on large maps with many active tiles.
+    pub parallel: bool,
+    /// Number of tiles handed to each worker task in the parallel path.
+    pub chunk_size: usize,
+}
+
+impl Default for AtmosphereConfig {
+    fn default() -> Self {
+        Self {
+            parallel: false,
+            chunk_size: 64,
+        }
+    }
+}
+
+/// How [`super::systems::update_tile_visuals`] colors tiles.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum VisualizationMode {
+    /// Color by atmospheric pressure (the default).
+    #[default]
+    Pressure,
+    /// Tint by temperature so hot tiles read red regardless of pressure.
+    Temperature,
+}
+
 /// Tile position in the grid
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TilePosition {
@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use super::gas::{GasMixture, GasType};
+
+/// Index into [`super::components::TileAtmosphere::neighbors`] — `[N, E, S, W]`.
+pub type DirIndex = usize;
+
+/// A gas-handling machine attached to a tile, addressing the tiles it moves gas
+/// between by neighbor direction. Layered on top of the passive diffusion of
+/// [`super::components::TileAtmosphere`]/[`super::gas::GasMixture`].
+#[derive(Component, Debug, Clone)]
+pub struct GasDevice {
+    pub kind: DeviceKind,
+}
+
+impl GasDevice {
+    /// A trinary filter pulling `filter_type` into one output and the remainder
+    /// into the other.
+    pub fn filter(
+        input: DirIndex,
+        filtered_output: DirIndex,
+        unfiltered_output: DirIndex,
+        filter_type: Vec<GasType>,
+    ) -> Self {
+        Self {
+            kind: DeviceKind::Filter {
+                input,
+                filtered_output,
+                unfiltered_output,
+                filter_type,
+                max_moles: DEFAULT_DEVICE_MAX_MOLES,
+                max_output_pressure: DEFAULT_MAX_OUTPUT_PRESSURE,
+            },
+        }
+    }
+
+    /// A binary pump moving gas from `input` to `output` up to `target_pressure`.
+    pub fn pump(input: DirIndex, output: DirIndex, target_pressure: u64) -> Self {
+        Self {
+            kind: DeviceKind::Pump {
+                input,
+                output,
+                max_moles: DEFAULT_DEVICE_MAX_MOLES,
+                target_pressure,
+            },
+        }
+    }
+
+    /// A vent equalizing the `tile` neighbor toward a connected pipe-network
+    /// mixture. The network acts as a per-tick reservoir: when the tile runs
+    /// hotter/fuller than the network it scrubs gas out, otherwise it supplies the
+    /// network's gas back into the room.
+    pub fn vent(tile: DirIndex, network: GasMixture) -> Self {
+        Self {
+            kind: DeviceKind::Vent {
+                tile,
+                network,
+                max_moles: DEFAULT_DEVICE_MAX_MOLES,
+            },
+        }
+    }
+}
+
+/// The behavior of a [`GasDevice`].
+#[derive(Debug, Clone)]
+pub enum DeviceKind {
+    /// Trinary filter: extract the `filter_type` species from the `input`
+    /// neighbor into `filtered_output`, and pass everything else to
+    /// `unfiltered_output`, moving at most `max_moles` per tick and refusing to
+    /// push an output above `max_output_pressure`.
+    Filter {
+        input: DirIndex,
+        filtered_output: DirIndex,
+        unfiltered_output: DirIndex,
+        filter_type: Vec<GasType>,
+        max_moles: u64,
+        max_output_pressure: u64,
+    },
+    /// Binary pump: transfer moles from `input` to `output` until the output
+    /// reaches `target_pressure`, moving at most `max_moles` per tick.
+    Pump {
+        input: DirIndex,
+        output: DirIndex,
+        max_moles: u64,
+        target_pressure: u64,
+    },
+    /// Vent: equalize the `tile` neighbor toward the connected pipe-`network`
+    /// mixture, moving at most `max_moles` per tick. Gas flows from the
+    /// higher-pressure side to the lower, so a vent both pressurizes a room from
+    /// its network and scrubs an overpressure back into it.
+    Vent {
+        tile: DirIndex,
+        network: GasMixture,
+        max_moles: u64,
+    },
+}
+
+/// Default per-tick throughput of a device, in micro-moles.
+pub const DEFAULT_DEVICE_MAX_MOLES: u64 = 500_000;
+/// Default pressure a device refuses to push an output past (~4.5 atm).
+pub const DEFAULT_MAX_OUTPUT_PRESSURE: u64 = 455_000_000;
+</content>
@@ -15,6 +15,24 @@ pub enum GasType {
 
 pub const GAS_TYPE_COUNT: usize = 7;
 
+impl GasType {
+    /// Every gas species, in discriminant order, for iteration.
+    pub const ALL: [GasType; GAS_TYPE_COUNT] = [
+        GasType::Oxygen,
+        GasType::Nitrogen,
+        GasType::CarbonDioxide,
+        GasType::Plasma,
+        GasType::NitrousOxide,
+        GasType::WaterVapor,
+        GasType::Tritium,
+    ];
+
+    /// Molar heat capacity `Cv` of this species, in scaled J/(mol·K).
+    pub const fn heat_capacity(self) -> u64 {
+        MOLAR_HEAT_CAPACITY[self as usize]
+    }
+}
+
 /// Gas mixture using fixed-size array and integer math for performance
 #[derive(Debug, Clone, Component)]
 pub struct GasMixture {
@@ -77,6 +95,21 @@ impl GasMixture {
     pub fn total_moles(&self) -> u64 {
         self.moles.iter().sum()
     }
+
+    /// Heat capacity of the mixture in scaled J/K: the mole-weighted sum
+    /// `Σ moles_i · Cv_i` over [`MOLAR_HEAT_CAPACITY`].
+    pub fn heat_capacity(&self) -> u64 {
+        let mut sum: u128 = 0;
+        for i in 0..GAS_TYPE_COUNT {
+            sum += self.moles[i] as u128 * MOLAR_HEAT_CAPACITY[i] as u128;
+        }
+        (sum / MICROMOLES_PER_MOLE as u128) as u64
+    }
+
+    /// Thermal energy of the mixture in scaled joules: `E = temperature · Cv`.
+    pub fn thermal_energy(&self) -> u128 {
+        self.temperature as u128 * self.heat_capacity() as u128
+    }
     
     /// Calculate pressure in micro-kPa
     pub fn pressure(&self) -> u64 {
@@ -94,10 +127,97 @@ impl GasMixture {
         ((n * r * t) / (1000 * v)) as u64
     }
     
+    /// Calculate pressure (μkPa) with the Peng-Robinson cubic equation of state.
+    ///
+    /// At the very high densities plasma/engine setups reach, ideal gas
+    /// ([`pressure`](Self::pressure)) overshoots; the PR EOS corrects for molecular
+    /// volume and attraction. Per gas we use the critical constants in
+    /// [`CRITICAL_TEMP_K`]/[`CRITICAL_PRESSURE_KPA`]/[`ACENTRIC_FACTOR`] to form
+    /// `a_i`, `b_i` and the temperature factor `α_i`, mix them with mole fractions
+    /// (`a_mix = ΣΣ x_i x_j √(a_i α_i · a_j α_j)`, `b_mix = Σ x_i b_i`), and evaluate
+    ///
+    /// ```text
+    /// P = R·T/(Vm − b_mix) − a_mix / (Vm² + 2·b_mix·Vm − b_mix²).
+    /// ```
+    ///
+    /// The nonlinear √/square terms are evaluated in `f64`; inputs and the result
+    /// stay in the crate's scaled units. The cubic is evaluated at every density,
+    /// low densities included (where it stays close to ideal gas). It defers to
+    /// [`pressure`](Self::pressure) only when `Vm` is within 10% of `b_mix`, where
+    /// the denominator `Vm − b_mix` nears zero, or when the result is not a
+    /// finite positive pressure.
+    pub fn real_pressure(&self) -> u64 {
+        let total = self.total_moles();
+        if total == 0 || self.volume == 0 {
+            return 0;
+        }
+
+        const R: f64 = 8.314; // J/(mol·K)
+        let t = self.temperature as f64 / MILLIKELVIN_PER_KELVIN as f64; // K
+        let v_m3 = self.volume as f64 / MICRO_M3_PER_M3 as f64; // m³
+        let n_mol = total as f64 / MICROMOLES_PER_MOLE as f64; // mol
+        let vm = v_m3 / n_mol; // molar volume, m³/mol
+
+        // Per-gas a·α (folding the temperature factor into a) and b, plus mole
+        // fractions for the mixing rules.
+        let mut a_alpha = [0.0f64; GAS_TYPE_COUNT];
+        let mut b = [0.0f64; GAS_TYPE_COUNT];
+        let mut x = [0.0f64; GAS_TYPE_COUNT];
+        for i in 0..GAS_TYPE_COUNT {
+            x[i] = self.moles[i] as f64 / total as f64;
+            if self.moles[i] == 0 {
+                continue;
+            }
+            let tc = CRITICAL_TEMP_K[i];
+            let pc = CRITICAL_PRESSURE_KPA[i] * 1000.0; // kPa → Pa
+            let omega = ACENTRIC_FACTOR[i];
+            let a_i = 0.45724 * R * R * tc * tc / pc;
+            let b_i = 0.07780 * R * tc / pc;
+            let kappa = 0.37464 + 1.54226 * omega - 0.26992 * omega * omega;
+            let sqrt_tr = (t / tc).sqrt();
+            let alpha = (1.0 + kappa * (1.0 - sqrt_tr)).powi(2);
+            a_alpha[i] = a_i * alpha;
+            b[i] = b_i;
+        }
+
+        let mut a_mix = 0.0f64;
+        for i in 0..GAS_TYPE_COUNT {
+            for j in 0..GAS_TYPE_COUNT {
+                a_mix += x[i] * x[j] * (a_alpha[i] * a_alpha[j]).sqrt();
+            }
+        }
+        let b_mix: f64 = (0..GAS_TYPE_COUNT).map(|i| x[i] * b[i]).sum();
+
+        // Vm close to b_mix (ill-conditioned repulsive term): defer to ideal gas.
+        if vm <= b_mix * 1.1 {
+            return self.pressure();
+        }
+
+        let repulsive = R * t / (vm - b_mix);
+        let attractive = a_mix / (vm * vm + 2.0 * b_mix * vm - b_mix * b_mix);
+        let p_pa = repulsive - attractive;
+        if !p_pa.is_finite() || p_pa <= 0.0 {
+            return self.pressure();
+        }
+
+        // Pa → μkPa (1 Pa = 1000 μkPa).
+        (p_pa * 1000.0) as u64
+    }
+
     /// Get moles of a specific gas
     pub fn get_moles(&self, gas_type: GasType) -> u64 {
         self.moles[gas_type as usize]
     }
+
+    /// Partial pressure of a single species in μkPa: the total pressure scaled by
+    /// that gas's mole fraction. Zero for an empty mixture.
+    pub fn partial_pressure(&self, gas_type: GasType) -> u64 {
+        let total = self.total_moles();
+        if total == 0 {
+            return 0;
+        }
+        ((self.pressure() as u128 * self.moles[gas_type as usize] as u128) / total as u128) as u64
+    }
     
     /// Add moles of a specific gas
     pub fn add_moles(&mut self, gas_type: GasType, amount: u64) {
@@ -109,6 +229,35 @@ impl GasMixture {
         self.moles[gas_type as usize] = self.moles[gas_type as usize].saturating_sub(amount);
     }
     
+    /// Move `amount` micro-moles of `gas_type` from this mixture into `dest`,
+    /// carrying the thermal energy this mixture loses with the gas (its drop in
+    /// heat capacity times its temperature), so the combined `thermal_energy` of
+    /// the two mixtures is conserved; `dest` takes the temperature of its new
+    /// energy over its new heat capacity. This mixture's own temperature is
+    /// unchanged, since removing gas at a uniform temperature removes exactly its energy.
+    /// Returns the moles actually moved (capped at what is present). Active
+    /// machinery ([`super::devices`]) moves gas this way so energy stays consistent.
+    pub fn transfer_to(&mut self, dest: &mut GasMixture, gas_type: GasType, amount: u64) -> u64 {
+        let moved = amount.min(self.get_moles(gas_type));
+        if moved == 0 {
+            return 0;
+        }
+        // Measure the carried energy as this mixture's actual drop in capacity,
+        // so integer rounding of heat_capacity() cannot create or lose energy.
+        let capacity_before = self.heat_capacity() as u128;
+        self.remove_moles(gas_type, moved);
+        let incoming_capacity = capacity_before - self.heat_capacity() as u128;
+        let incoming_energy = self.temperature as u128 * incoming_capacity;
+
+        let dest_energy = dest.thermal_energy();
+        dest.add_moles(gas_type, moved);
+        let new_capacity = dest.heat_capacity() as u128;
+        if new_capacity > 0 {
+            dest.temperature = ((dest_energy + incoming_energy) / new_capacity).max(1) as u64;
+        }
+        moved
+    }
+
     /// Share gas with another mixture based on pressure differential
     /// This implements a simplified Monson method for gas equalization
     pub fn share_gas_with(&mut self, other: &mut GasMixture) {
@@ -156,33 +305,95 @@ impl GasMixture {
         self.share_heat_with(other);
     }
     
-    /// Share heat with another mixture based on temperature differential
+    /// Share heat with another mixture, conserving total thermal energy.
+    ///
+    /// With per-gas heat capacities a tiny hot mixture no longer drags a huge cold
+    /// one by the same amount: the combined energy `E_a + E_b` fixes the
+    /// equilibrium temperature `(E_a + E_b) / (cap_a + cap_b)`, and each mixture
+    /// relaxes a fixed fraction ([`HEAT_SHARE_FRACTION`]) of the way toward it per
+    /// tick. Because `cap_a·(Teq − Ta) + cap_b·(Teq − Tb) = 0`, energy is
+    /// conserved exactly regardless of the relaxation fraction.
     pub fn share_heat_with(&mut self, other: &mut GasMixture) {
-        let total_moles_a = self.total_moles();
-        let total_moles_b = other.total_moles();
-        
-        if total_moles_a == 0 || total_moles_b == 0 {
+        let cap_a = self.heat_capacity() as i128;
+        let cap_b = other.heat_capacity() as i128;
+        if cap_a == 0 || cap_b == 0 {
             return;
         }
-        
-        // Calculate temperature difference (in milli-Kelvin)
+
         let temp_diff = self.temperature as i128 - other.temperature as i128;
-        
-        if temp_diff.abs() < 100 {  // Less than 0.1K difference
+        if temp_diff.abs() < 100 {
+            // Less than 0.1K difference
             return;
         }
-        
-        // Simplified heat transfer - transfer proportional to temperature difference
-        // In reality this would use thermal conductivity, but for POC we use simplified approach
-        let heat_transfer = temp_diff / 10;
-        
-        self.temperature = (self.temperature as i128 - heat_transfer).max(1) as u64;
-        other.temperature = (other.temperature as i128 + heat_transfer).max(1) as u64;
+
+        let equilibrium =
+            (self.temperature as i128 * cap_a + other.temperature as i128 * cap_b) / (cap_a + cap_b);
+        let delta_a = (equilibrium - self.temperature as i128) * HEAT_SHARE_FRACTION / 1000;
+        let delta_b = (equilibrium - other.temperature as i128) * HEAT_SHARE_FRACTION / 1000;
+
+        self.temperature = (self.temperature as i128 + delta_a).max(1) as u64;
+        other.temperature = (other.temperature as i128 + delta_b).max(1) as u64;
     }
 }
 
 /// Helper constants for unit conversion
 pub const MICROMOLES_PER_MOLE: u64 = 1_000_000;
+
+/// Molar heat capacity `Cv` per [`GasType`], in scaled J/(mol·K), indexed by the
+/// enum discriminant. Monatomic-ish species sit near 12.5, diatomic N₂/O₂ near
+/// 20.8, the triatomics CO₂/N₂O near 28; plasma and tritium carry custom values.
+/// Values are rounded to integers for the crate's integer-math pipeline.
+pub const MOLAR_HEAT_CAPACITY: [u64; GAS_TYPE_COUNT] = [
+    21, // Oxygen (diatomic)
+    21, // Nitrogen (diatomic)
+    28, // CarbonDioxide (triatomic)
+    40, // Plasma (custom, stores a lot of heat)
+    28, // NitrousOxide (triatomic)
+    25, // WaterVapor
+    13, // Tritium (light, monatomic-ish)
+];
+
+/// Fraction (per-mille) of the way a mixture relaxes toward the equilibrium
+/// temperature per heat-sharing tick.
+pub const HEAT_SHARE_FRACTION: i128 = 400;
+
+/// Critical temperature `Tc` (K) per [`GasType`], for the Peng-Robinson EOS.
+/// Plasma is a fictional gas; its constants are chosen to stay well-behaved.
+pub const CRITICAL_TEMP_K: [f64; GAS_TYPE_COUNT] = [
+    154.6, // Oxygen
+    126.2, // Nitrogen
+    304.1, // CarbonDioxide
+    500.0, // Plasma (fictional)
+    309.6, // NitrousOxide
+    647.1, // WaterVapor
+    33.2,  // Tritium (hydrogen-like)
+];
+
+/// Critical pressure `Pc` (kPa) per [`GasType`].
+pub const CRITICAL_PRESSURE_KPA: [f64; GAS_TYPE_COUNT] = [
+    5043.0, // Oxygen
+    3390.0, // Nitrogen
+    7380.0, // CarbonDioxide
+    8000.0, // Plasma (fictional)
+    7240.0, // NitrousOxide
+    22060.0, // WaterVapor
+    1300.0, // Tritium
+];
+
+/// Acentric factor `ω` per [`GasType`].
+pub const ACENTRIC_FACTOR: [f64; GAS_TYPE_COUNT] = [
+    0.022,  // Oxygen
+    0.037,  // Nitrogen
+    0.228,  // CarbonDioxide
+    0.100,  // Plasma (fictional)
+    0.142,  // NitrousOxide
+    0.344,  // WaterVapor
+    -0.220, // Tritium
+];
+
+/// Conductivity (per-mille) of the gas-to-gas thermal path between two open
+/// tiles. Walls conduct at the lower rate stored on their `ThermalConductor`.
+pub const GAS_THERMAL_CONDUCTIVITY: u64 = 400;
 pub const MILLIKELVIN_PER_KELVIN: u64 = 1_000;
 pub const MICRO_M3_PER_M3: u64 = 1_000_000;
 
@@ -191,6 +402,22 @@ pub const STANDARD_PRESSURE_MICRO_KPA: u64 = 101_325_000; // 101.325 kPa
 pub const STANDARD_TEMP_MK: u64 = 293_150; // 20°C
 pub const STANDARD_VOLUME_MICRO_M3: u64 = 2_500_000; // 2.5 m³ per tile
 
+/// Pressure difference (μkPa) at which a tile triggers explosive equalization
+/// instead of single-step diffusion — roughly half an atmosphere.
+pub const EXPLOSIVE_PRESSURE_DELTA_MICRO_KPA: i128 = 50_000_000; // 50 kPa
+/// Maximum number of tiles collected into one explosive-equalization group.
+pub const EXPLOSIVE_MAX_TILES: usize = 20;
+/// Pressure (μkPa) at or below which a tile counts as vacuum/space for
+/// explosive equalization — a breached pocket reaching it dumps outward to
+/// zero. Roughly 1 kPa, far below any habitable atmosphere.
+pub const VACUUM_PRESSURE_MICRO_KPA: i128 = 1_000_000;
+
+/// Pressure window (μkPa) within which neighboring tiles are pulled into the
+/// same flood-fill equalization group — roughly 5 kPa.
+pub const FLOOD_PRESSURE_THRESHOLD_MICRO_KPA: i128 = 5_000_000;
+/// Maximum tiles settled by one flood-fill group per tick (for determinism).
+pub const FLOOD_MAX_TILES: usize = 100;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -212,6 +439,28 @@ mod tests {
         );
     }
     
+    #[test]
+    fn test_real_pressure_tracks_ideal_at_standard_density() {
+        let mixture = GasMixture::new_air(STANDARD_VOLUME_MICRO_M3, STANDARD_TEMP_MK);
+        let ideal = mixture.pressure();
+        let real = mixture.real_pressure();
+
+        // At ordinary density the two models agree closely.
+        let diff = (ideal as i128 - real as i128).unsigned_abs();
+        assert!(
+            diff < ideal as u128 / 10,
+            "real pressure {} should be within 10% of ideal {}",
+            real,
+            ideal
+        );
+    }
+
+    #[test]
+    fn test_real_pressure_handles_vacuum() {
+        let vacuum = GasMixture::new(STANDARD_VOLUME_MICRO_M3, STANDARD_TEMP_MK);
+        assert_eq!(vacuum.real_pressure(), 0);
+    }
+
     #[test]
     fn test_total_moles() {
         let mut mixture = GasMixture::default();
@@ -270,4 +519,75 @@ mod tests {
         assert!(final_diff < initial_diff,
             "Temperature difference should decrease after heat sharing");
     }
+
+    #[test]
+    fn test_heat_sharing_conserves_energy() {
+        let mut hot = GasMixture::new_air(STANDARD_VOLUME_MICRO_M3, 400_000);
+        // A tiny, very hot mixture next to a large cold one.
+        let mut small_hot = GasMixture::new(STANDARD_VOLUME_MICRO_M3, 900_000);
+        small_hot.add_moles(GasType::Oxygen, 10_000);
+
+        let energy_before = hot.thermal_energy() + small_hot.thermal_energy();
+        for _ in 0..20 {
+            hot.share_heat_with(&mut small_hot);
+        }
+        let energy_after = hot.thermal_energy() + small_hot.thermal_energy();
+
+        // Integer relaxation conserves energy to within rounding of a few units.
+        let drift = energy_before.abs_diff(energy_after);
+        assert!(drift < 1_000, "energy drift {} should be negligible", drift);
+
+        // The small hot pocket barely moves the big cold mass.
+        assert!(hot.temperature < 420_000, "huge cold mass should warm only slightly");
+    }
+
+    #[test]
+    fn test_transfer_to_conserves_energy_and_moves_gas() {
+        // Hot source, cold empty destination: the moved gas should carry its heat.
+        let mut src = GasMixture::new(STANDARD_VOLUME_MICRO_M3, 600_000);
+        src.add_moles(GasType::Oxygen, 1_000_000);
+        let mut dst = GasMixture::new(STANDARD_VOLUME_MICRO_M3, 300_000);
+
+        let energy_before = src.thermal_energy() + dst.thermal_energy();
+        let moved = src.transfer_to(&mut dst, GasType::Oxygen, 400_000);
+
+        assert_eq!(moved, 400_000);
+        assert_eq!(src.get_moles(GasType::Oxygen), 600_000);
+        assert_eq!(dst.get_moles(GasType::Oxygen), 400_000);
+        // Destination warms toward the source temperature; source is unchanged.
+        assert_eq!(src.temperature, 600_000);
+        assert!(dst.temperature > 300_000);
+
+        let energy_after = src.thermal_energy() + dst.thermal_energy();
+        let drift = energy_before.abs_diff(energy_after);
+        assert!(drift < 1_000, "energy drift {} should be negligible", drift);
+    }
+
+    #[test]
+    fn test_partial_pressure_splits_by_mole_fraction() {
+        let mut mix = GasMixture::new(STANDARD_VOLUME_MICRO_M3, STANDARD_TEMP_MK);
+        mix.add_moles(GasType::Oxygen, 1_000_000);
+        mix.add_moles(GasType::Nitrogen, 3_000_000);
+
+        let total = mix.pressure();
+        let o2 = mix.partial_pressure(GasType::Oxygen);
+        let n2 = mix.partial_pressure(GasType::Nitrogen);
+
+        // Oxygen is a quarter of the moles, so a quarter of the pressure.
+        assert!(o2.abs_diff(total / 4) < 10);
+        assert!(n2.abs_diff(total * 3 / 4) < 10);
+        assert_eq!(mix.partial_pressure(GasType::Plasma), 0);
+    }
+
+    #[test]
+    fn test_transfer_to_caps_at_available_moles() {
+        let mut src = GasMixture::new(STANDARD_VOLUME_MICRO_M3, 300_000);
+        src.add_moles(GasType::Nitrogen, 50_000);
+        let mut dst = GasMixture::new(STANDARD_VOLUME_MICRO_M3, 300_000);
+
+        let moved = src.transfer_to(&mut dst, GasType::Nitrogen, 999_999);
+        assert_eq!(moved, 50_000);
+        assert_eq!(src.get_moles(GasType::Nitrogen), 0);
+        assert_eq!(dst.get_moles(GasType::Nitrogen), 50_000);
+    }
 }
@@ -1,7 +1,12 @@
 /// Atmospheric simulation module for tile-based gas simulation
+pub mod breathing;
 pub mod components;
+pub mod devices;
 pub mod gas;
 pub mod plugin;
+pub mod queue;
+pub mod reactions;
 pub mod systems;
+pub mod zones;
 
 pub use plugin::AtmospherePlugin;
@@ -1,17 +1,82 @@
 use bevy::prelude::*;
+use super::breathing::process_breathing;
+use super::components::{AtmosphereConfig, VisualizationMode};
+use super::queue::ActiveTileQueue;
+use super::reactions::{ReactionRegistry, TileIgnited};
 use super::systems::*;
+use super::zones::Zones;
 
-/// Atmospheric simulation plugin
-pub struct AtmospherePlugin;
+/// Atmospheric simulation plugin.
+///
+/// Configure the gas-sharing path and its parallel chunk size through
+/// [`AtmosphereConfig`]; use [`AtmospherePlugin::default`] for the sequential
+/// defaults.
+#[derive(Default)]
+pub struct AtmospherePlugin {
+    pub config: AtmosphereConfig,
+}
 
 impl Plugin for AtmospherePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (
-            initialize_neighbors,
-            mark_dirty_tiles,
-            process_gas_sharing,
-            update_tile_visuals,
-            debug_atmosphere,
-        ).chain());
+        app.init_resource::<Zones>();
+        app.init_resource::<ActiveTileQueue>();
+        app.init_resource::<VisualizationMode>();
+        app.insert_resource(self.config);
+        app.insert_resource(ReactionRegistry::with_defaults());
+        app.add_event::<TileIgnited>();
+
+        // The prologue, machinery, chemistry, thermal and visual stages are
+        // shared; only the gas-sharing stage differs between the sequential and
+        // parallel paths, selected by AtmosphereConfig::parallel.
+        app.add_systems(
+            Update,
+            (
+                initialize_neighbors,
+                update_wall_connections,
+                rebuild_zones,
+                apply_zone_mixtures,
+                process_zone_edges,
+                mark_dirty_tiles,
+                process_gas_devices,
+            )
+                .chain(),
+        );
+
+        // Settle calm interiors in one tick before the pairwise path runs.
+        app.add_systems(
+            Update,
+            process_flood_equalization
+                .after(process_gas_devices)
+                .before(process_reactions),
+        );
+
+        if self.config.parallel {
+            app.add_systems(
+                Update,
+                process_gas_sharing_parallel
+                    .after(process_flood_equalization)
+                    .before(process_reactions),
+            );
+        } else {
+            app.add_systems(
+                Update,
+                process_gas_sharing
+                    .after(process_flood_equalization)
+                    .before(process_reactions),
+            );
+        }
+
+        app.add_systems(
+            Update,
+            (
+                process_reactions,
+                process_breathing,
+                process_heat_conduction,
+                update_tile_visuals,
+                debug_atmosphere,
+            )
+                .chain(),
+        );
     }
 }
+</content>
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use std::collections::{HashSet, VecDeque};
+
+/// Work queue of tiles that need atmospheric processing this tick.
+///
+/// Replaces the per-frame scan for the old `AtmosphereActive` marker: instead of
+/// inserting and removing a component through `Commands` every tick, systems push
+/// tiles here and drain them. A backing [`HashSet`] rejects duplicate pushes so a
+/// tile is queued at most once no matter how many neighbors wake it.
+#[derive(Resource, Default)]
+pub struct ActiveTileQueue {
+    queue: VecDeque<Entity>,
+    pending: HashSet<Entity>,
+}
+
+impl ActiveTileQueue {
+    /// Enqueue a tile, returning `true` if it was newly added. Tiles already
+    /// pending are rejected so no tile is processed twice in one drain.
+    pub fn push(&mut self, tile: Entity) -> bool {
+        if self.pending.insert(tile) {
+            self.queue.push_back(tile);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Dequeue the next pending tile, if any.
+    pub fn pop(&mut self) -> Option<Entity> {
+        let tile = self.queue.pop_front()?;
+        self.pending.remove(&tile);
+        Some(tile)
+    }
+
+    /// Is this tile currently queued?
+    pub fn contains(&self, tile: Entity) -> bool {
+        self.pending.contains(&tile)
+    }
+
+    /// Snapshot of the currently-pending tiles.
+    pub fn pending(&self) -> Vec<Entity> {
+        self.queue.iter().copied().collect()
+    }
+
+    /// Number of queued tiles.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// True if nothing is queued.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Drain every currently-pending tile into a vector, emptying the queue.
+    pub fn drain(&mut self) -> Vec<Entity> {
+        self.pending.clear();
+        self.queue.drain(..).collect()
+    }
+}
+</content>
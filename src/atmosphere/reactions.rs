@@ -0,0 +1,298 @@
+use bevy::prelude::*;
+use super::gas::{GasMixture, GasType};
+
+/// Outcome of running one [`Reaction`] against a mixture.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ReactionResult {
+    /// Total micro-moles of reactant consumed.
+    pub moles_reacted: u64,
+    /// Scaled joules of enthalpy released into the mixture.
+    pub energy_released: u64,
+    /// True if the reaction altered the mixture at all.
+    pub changed: bool,
+    /// True only for a genuinely exothermic burn (an ignition). Endothermic
+    /// reactions report their heat draw through `energy_released` but leave this
+    /// clear, so they never emit a [`TileIgnited`] event.
+    pub ignited: bool,
+}
+
+/// A gas-phase reaction hook, in the spirit of auxmos's reaction hooks.
+///
+/// Implementors decide when they fire ([`can_react`](Reaction::can_react)) and
+/// apply their stoichiometry in place ([`react`](Reaction::react)). They are
+/// registered in the [`ReactionRegistry`] so mods can add their own chemistry.
+pub trait Reaction: Send + Sync {
+    /// Cheap pre-check: does this reaction apply to the mixture right now?
+    fn can_react(&self, mixture: &GasMixture) -> bool;
+
+    /// Apply the reaction, mutating the mixture and returning what happened.
+    fn react(&self, mixture: &mut GasMixture) -> ReactionResult;
+}
+
+/// Resource holding every registered reaction, run in order each tick.
+#[derive(Resource, Default)]
+pub struct ReactionRegistry {
+    reactions: Vec<Box<dyn Reaction>>,
+}
+
+impl ReactionRegistry {
+    /// Register a reaction hook.
+    pub fn register(&mut self, reaction: impl Reaction + 'static) {
+        self.reactions.push(Box::new(reaction));
+    }
+
+    /// True if any registered reaction would fire on `mixture` right now. Used to
+    /// seed [`super::systems::process_reactions`] from a tile's composition and
+    /// temperature, so an ignitable but pressure-static mixture still lights
+    /// instead of depending on a leftover gradient to keep it in the work queue.
+    pub fn can_react(&self, mixture: &GasMixture) -> bool {
+        self.reactions.iter().any(|r| r.can_react(mixture))
+    }
+
+    /// Run every applicable reaction against `mixture`, returning the combined
+    /// result (whether anything changed and the total enthalpy released).
+    pub fn react(&self, mixture: &mut GasMixture) -> ReactionResult {
+        let mut combined = ReactionResult::default();
+        for reaction in &self.reactions {
+            if reaction.can_react(mixture) {
+                let result = reaction.react(mixture);
+                combined.moles_reacted = combined.moles_reacted.saturating_add(result.moles_reacted);
+                combined.energy_released =
+                    combined.energy_released.saturating_add(result.energy_released);
+                combined.changed |= result.changed;
+                combined.ignited |= result.ignited;
+            }
+        }
+        combined
+    }
+
+    /// Build a registry with the default reaction set: plasma fire, tritium
+    /// combustion and nitrous-oxide decomposition. Mods can gate individual
+    /// hooks by registering their own set instead, the way auxmos toggles
+    /// `all_reaction_hooks` per build.
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::default();
+        registry.register(PlasmaFire);
+        registry.register(TritiumFire);
+        registry.register(NitrousOxideDecomposition);
+        registry
+    }
+}
+
+/// Emitted when a tile's mixture ignites (a reaction raised its temperature
+/// through an exothermic burn).
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TileIgnited {
+    pub tile: Entity,
+}
+
+/// Ignition temperature for plasma combustion: 373 K in milli-Kelvin.
+pub const PLASMA_IGNITION_TEMP_MK: u64 = 373_000;
+/// Ignition temperature for tritium combustion, in milli-Kelvin.
+pub const TRITIUM_IGNITION_TEMP_MK: u64 = 373_000;
+/// Temperature above which nitrous oxide thermally decomposes, in milli-Kelvin.
+pub const N2O_DECOMPOSITION_TEMP_MK: u64 = 850_000;
+/// Minimum mole fraction (in percent) of both reactants for ignition.
+const FIRE_MIN_PERCENT: u64 = 1;
+/// Oxygen micro-moles consumed per micro-mole of plasma burned.
+const PLASMA_OXYGEN_RATIO: u64 = 2;
+/// Plasma:oxygen ratio above which the burn yields tritium instead of CO₂.
+const PLASMA_TRITIUM_RATIO: u64 = 4;
+/// Enthalpy released per micro-mole of plasma burned, in `thermal_energy` units.
+const PLASMA_FIRE_ENERGY: u64 = 50;
+/// Enthalpy released per micro-mole of tritium burned — far hotter than plasma.
+const TRITIUM_FIRE_ENERGY: u64 = 250;
+/// Enthalpy absorbed per micro-mole of nitrous oxide decomposed (endothermic).
+const N2O_DECOMPOSITION_ENERGY: i64 = -8;
+
+/// Plasma fire: with plasma and oxygen both present above a minimum fraction and
+/// the mixture above [`PLASMA_IGNITION_TEMP_MK`], burn plasma and oxygen at a
+/// fixed stoichiometric ratio, releasing enthalpy that raises the temperature —
+/// the hotter it burns, the faster it consumes fuel. An oxygen-starved burn
+/// (plasma:oxygen above [`PLASMA_TRITIUM_RATIO`]) yields tritium; otherwise it
+/// yields carbon dioxide.
+pub struct PlasmaFire;
+
+impl Reaction for PlasmaFire {
+    fn can_react(&self, mixture: &GasMixture) -> bool {
+        if mixture.temperature < PLASMA_IGNITION_TEMP_MK {
+            return false;
+        }
+        let total = mixture.total_moles();
+        if total == 0 {
+            return false;
+        }
+        let plasma = mixture.get_moles(GasType::Plasma);
+        let oxygen = mixture.get_moles(GasType::Oxygen);
+        plasma * 100 >= total * FIRE_MIN_PERCENT && oxygen * 100 >= total * FIRE_MIN_PERCENT
+    }
+
+    fn react(&self, mixture: &mut GasMixture) -> ReactionResult {
+        let plasma = mixture.get_moles(GasType::Plasma);
+        let oxygen = mixture.get_moles(GasType::Oxygen);
+
+        // Burn rate scales with how far above ignition we are (1x at ignition).
+        let temp_factor = mixture.temperature / PLASMA_IGNITION_TEMP_MK;
+        // Limited by whichever reactant runs out first, honoring the O₂ ratio.
+        let plasma_burned = (plasma / 10 * temp_factor)
+            .min(plasma)
+            .min(oxygen / PLASMA_OXYGEN_RATIO);
+        if plasma_burned == 0 {
+            return ReactionResult::default();
+        }
+        let oxygen_burned = plasma_burned * PLASMA_OXYGEN_RATIO;
+
+        mixture.remove_moles(GasType::Plasma, plasma_burned);
+        mixture.remove_moles(GasType::Oxygen, oxygen_burned);
+        // Oxygen-starved burns run hot and rich, producing tritium.
+        if plasma >= oxygen.saturating_mul(PLASMA_TRITIUM_RATIO) {
+            mixture.add_moles(GasType::Tritium, plasma_burned);
+        } else {
+            mixture.add_moles(GasType::CarbonDioxide, plasma_burned + oxygen_burned);
+        }
+
+        let energy = plasma_burned.saturating_mul(PLASMA_FIRE_ENERGY);
+        release_energy(mixture, energy as i64);
+
+        ReactionResult {
+            moles_reacted: plasma_burned + oxygen_burned,
+            energy_released: energy,
+            changed: true,
+            ignited: true,
+        }
+    }
+}
+
+/// Tritium combustion: tritium and oxygen above [`TRITIUM_IGNITION_TEMP_MK`] burn
+/// into water vapor with a very high heat release.
+pub struct TritiumFire;
+
+impl Reaction for TritiumFire {
+    fn can_react(&self, mixture: &GasMixture) -> bool {
+        if mixture.temperature < TRITIUM_IGNITION_TEMP_MK {
+            return false;
+        }
+        let total = mixture.total_moles();
+        if total == 0 {
+            return false;
+        }
+        let tritium = mixture.get_moles(GasType::Tritium);
+        let oxygen = mixture.get_moles(GasType::Oxygen);
+        tritium * 100 >= total * FIRE_MIN_PERCENT && oxygen * 100 >= total * FIRE_MIN_PERCENT
+    }
+
+    fn react(&self, mixture: &mut GasMixture) -> ReactionResult {
+        let tritium = mixture.get_moles(GasType::Tritium);
+        let oxygen = mixture.get_moles(GasType::Oxygen);
+
+        let temp_factor = mixture.temperature / TRITIUM_IGNITION_TEMP_MK;
+        // 2 T + O₂ → 2 H₂O: oxygen is the limiting half of the tritium count.
+        let tritium_burned = (tritium / 10 * temp_factor).min(tritium).min(oxygen * 2);
+        if tritium_burned == 0 {
+            return ReactionResult::default();
+        }
+        let oxygen_burned = tritium_burned / 2;
+
+        mixture.remove_moles(GasType::Tritium, tritium_burned);
+        mixture.remove_moles(GasType::Oxygen, oxygen_burned);
+        mixture.add_moles(GasType::WaterVapor, tritium_burned);
+
+        let energy = tritium_burned.saturating_mul(TRITIUM_FIRE_ENERGY);
+        release_energy(mixture, energy as i64);
+
+        ReactionResult {
+            moles_reacted: tritium_burned + oxygen_burned,
+            energy_released: energy,
+            changed: true,
+            ignited: true,
+        }
+    }
+}
+
+/// Nitrous-oxide thermal decomposition: above [`N2O_DECOMPOSITION_TEMP_MK`],
+/// 2 N₂O → 2 N₂ + O₂, absorbing a little heat.
+pub struct NitrousOxideDecomposition;
+
+impl Reaction for NitrousOxideDecomposition {
+    fn can_react(&self, mixture: &GasMixture) -> bool {
+        mixture.temperature >= N2O_DECOMPOSITION_TEMP_MK
+            && mixture.get_moles(GasType::NitrousOxide) > 0
+    }
+
+    fn react(&self, mixture: &mut GasMixture) -> ReactionResult {
+        let n2o = mixture.get_moles(GasType::NitrousOxide);
+        let temp_factor = mixture.temperature / N2O_DECOMPOSITION_TEMP_MK;
+        let decomposed = (n2o / 10 * temp_factor).min(n2o);
+        if decomposed == 0 {
+            return ReactionResult::default();
+        }
+
+        mixture.remove_moles(GasType::NitrousOxide, decomposed);
+        mixture.add_moles(GasType::Nitrogen, decomposed);
+        mixture.add_moles(GasType::Oxygen, decomposed / 2);
+
+        let energy = (decomposed as i64).saturating_mul(N2O_DECOMPOSITION_ENERGY);
+        release_energy(mixture, energy);
+
+        ReactionResult {
+            moles_reacted: decomposed,
+            energy_released: energy.unsigned_abs(),
+            changed: true,
+            // Endothermic: cools the tile, so it must not register as an ignition.
+            ignited: false,
+        }
+    }
+}
+
+/// Deposit `energy` (in `thermal_energy` units, possibly negative for an
+/// endothermic reaction) into the mixture, feeding the energy-conserving
+/// heat-capacity model: the new temperature is `(E + energy) / Cv`.
+fn release_energy(mixture: &mut GasMixture, energy: i64) {
+    let capacity = mixture.heat_capacity() as i128;
+    if capacity == 0 {
+        return;
+    }
+    let new_energy = (mixture.thermal_energy() as i128 + energy as i128).max(0);
+    mixture.temperature = (new_energy / capacity).max(1) as u64;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::atmosphere::gas::{STANDARD_VOLUME_MICRO_M3};
+
+    #[test]
+    fn plasma_fire_needs_oxygen_and_heat() {
+        let fire = PlasmaFire;
+        let mut cold = GasMixture::new(STANDARD_VOLUME_MICRO_M3, 300_000);
+        cold.add_moles(GasType::Plasma, 1_000_000);
+        cold.add_moles(GasType::Oxygen, 1_000_000);
+        // Below ignition temperature.
+        assert!(!fire.can_react(&cold));
+
+        let mut inert = GasMixture::new(STANDARD_VOLUME_MICRO_M3, 400_000);
+        inert.add_moles(GasType::Plasma, 1_000_000);
+        // No oxygen present.
+        assert!(!fire.can_react(&inert));
+    }
+
+    #[test]
+    fn plasma_fire_burns_and_heats() {
+        let fire = PlasmaFire;
+        let mut mixture = GasMixture::new(STANDARD_VOLUME_MICRO_M3, 400_000);
+        mixture.add_moles(GasType::Plasma, 1_000_000);
+        mixture.add_moles(GasType::Oxygen, 1_000_000);
+
+        let before_temp = mixture.temperature;
+        let before_co2 = mixture.get_moles(GasType::CarbonDioxide);
+
+        assert!(fire.can_react(&mixture));
+        let result = fire.react(&mut mixture);
+
+        assert!(result.changed);
+        assert!(mixture.get_moles(GasType::Plasma) < 1_000_000);
+        assert!(mixture.get_moles(GasType::CarbonDioxide) > before_co2);
+        assert!(mixture.temperature > before_temp, "combustion should heat the mixture");
+    }
+}
+</content>
@@ -1,101 +1,569 @@
 use bevy::prelude::*;
+use bevy::tasks::{ComputeTaskPool, ParallelSlice};
+use std::sync::atomic::{AtomicI64, Ordering};
 use super::components::*;
-use super::gas::GasMixture;
+use super::gas::{
+    GasMixture, EXPLOSIVE_MAX_TILES, EXPLOSIVE_PRESSURE_DELTA_MICRO_KPA, FLOOD_MAX_TILES,
+    FLOOD_PRESSURE_THRESHOLD_MICRO_KPA, GAS_THERMAL_CONDUCTIVITY, GAS_TYPE_COUNT,
+    VACUUM_PRESSURE_MICRO_KPA,
+};
+use super::devices::{DeviceKind, GasDevice};
+use super::gas::GasType;
+use super::queue::ActiveTileQueue;
+use super::reactions::{ReactionRegistry, TileIgnited};
+use super::zones::{TileSnapshot, ZoneMember, Zones};
 
-/// System to process gas sharing between connected tiles
-/// Only processes tiles with AtmosphereActive marker
+/// System to process gas sharing between connected tiles.
+///
+/// Only tiles drained from the [`ActiveTileQueue`] drive exchange. Each such
+/// tile first checks its open neighbors for a very large pressure gap
+/// (> [`EXPLOSIVE_PRESSURE_DELTA_MICRO_KPA`]); if found — a hull breach, say — it
+/// runs [`explosive_equalization`] to drain a connected pocket in a single tick.
+/// Otherwise it falls back to the gentle single-step [`GasMixture::share_gas_with`]
+/// diffusion, marking neighbors active so the gradient propagates.
 pub fn process_gas_sharing(
-    mut active_tiles: Query<(Entity, &mut TileAtmosphere), With<AtmosphereActive>>,
-    mut other_tiles: Query<&mut TileAtmosphere, Without<AtmosphereActive>>,
-    mut commands: Commands,
+    mut tiles: Query<(Entity, &mut TileAtmosphere, &mut FlowVector)>,
+    space: Query<Entity, With<ExposedToSpace>>,
+    mut active_queue: ResMut<ActiveTileQueue>,
 ) {
-    // Collect updates from active tiles
-    let mut updates: Vec<(Entity, GasMixture, Vec<(Entity, GasMixture, bool)>, bool)> = Vec::new();
-    
-    for (entity, atmosphere) in active_tiles.iter() {
-        let mut neighbor_data = Vec::new();
+    // Snapshot the working set: topology stays constant this tick, mixtures are
+    // cloned so we can compute every transfer before writing anything back.
+    let mut mixtures: std::collections::HashMap<Entity, GasMixture> = std::collections::HashMap::new();
+    let mut topology: std::collections::HashMap<Entity, [Option<(Entity, bool)>; 4]> =
+        std::collections::HashMap::new();
+    for (entity, atmosphere, _) in tiles.iter() {
+        mixtures.insert(entity, atmosphere.mixture.clone());
+        topology.insert(entity, atmosphere.neighbors);
+    }
+    let space_tiles: std::collections::HashSet<Entity> = space.iter().collect();
+
+    // Drain the work queue: only these tiles are processed this tick.
+    let active_list: Vec<Entity> = active_queue.drain();
+
+    // Per-tile dominant flow edge produced this tick (direction index into neighbors).
+    let mut flow_edges: std::collections::HashMap<Entity, usize> = std::collections::HashMap::new();
+    // Tiles that should become (or stay) active next tick.
+    let mut activate: std::collections::HashSet<Entity> = std::collections::HashSet::new();
+    // Tiles already consumed by an explosive group this tick.
+    let mut consumed: std::collections::HashSet<Entity> = std::collections::HashSet::new();
+
+    for &entity in &active_list {
+        if consumed.contains(&entity) {
+            continue;
+        }
+
+        let my_pressure = mixtures[&entity].pressure() as i128;
+
+        // Find the open neighbor with the largest pressure gap.
+        let mut max_gap = 0i128;
+        for neighbor_opt in topology[&entity].iter() {
+            if let Some((neighbor_entity, true)) = neighbor_opt {
+                if let Some(neighbor_mix) = mixtures.get(neighbor_entity) {
+                    let gap = my_pressure - neighbor_mix.pressure() as i128;
+                    if gap > max_gap {
+                        max_gap = gap;
+                    }
+                }
+            }
+        }
+
+        if max_gap >= EXPLOSIVE_PRESSURE_DELTA_MICRO_KPA {
+            // Breach-style rush: equalize a whole connected pocket at once.
+            explosive_equalization(
+                entity,
+                &topology,
+                &space_tiles,
+                &mut mixtures,
+                &mut flow_edges,
+                &mut activate,
+                &mut consumed,
+            );
+            continue;
+        }
+
+        // Gentle diffusion path (unchanged semantics): share with each open
+        // neighbor that still has a meaningful gradient.
         let mut has_active_exchange = false;
-        let my_pressure = atmosphere.mixture.pressure();
-        
-        for neighbor_opt in atmosphere.neighbors.iter() {
-            if let Some((neighbor_entity, is_open)) = neighbor_opt {
-                if *is_open {
-                    // Try to get from other_tiles (without active marker)
-                    if let Ok(neighbor_atmos) = other_tiles.get(*neighbor_entity) {
-                        let neighbor_pressure = neighbor_atmos.mixture.pressure();
-                        let pressure_diff = (my_pressure as i128 - neighbor_pressure as i128).abs();
-                        
-                        // Check if there's significant pressure difference (> 0.1 kPa = 100,000 μkPa)
-                        if pressure_diff > 100_000 {
-                            has_active_exchange = true;
-                            neighbor_data.push((*neighbor_entity, neighbor_atmos.mixture.clone(), true));
-                        } else {
-                            neighbor_data.push((*neighbor_entity, neighbor_atmos.mixture.clone(), false));
-                        }
+        let neighbors = topology[&entity];
+        for neighbor_opt in neighbors.iter() {
+            if let Some((neighbor_entity, true)) = neighbor_opt {
+                let neighbor_entity = *neighbor_entity;
+                let neighbor_pressure = match mixtures.get(&neighbor_entity) {
+                    Some(m) => m.pressure() as i128,
+                    None => continue,
+                };
+                if (my_pressure - neighbor_pressure).abs() > 100_000 {
+                    has_active_exchange = true;
+                    let mut mine = mixtures[&entity].clone();
+                    let mut theirs = mixtures[&neighbor_entity].clone();
+                    mine.share_gas_with(&mut theirs);
+                    mixtures.insert(entity, mine);
+                    mixtures.insert(neighbor_entity, theirs);
+                    activate.insert(neighbor_entity);
+                }
+            }
+        }
+        if has_active_exchange {
+            // Still exchanging — stay active next tick.
+            activate.insert(entity);
+        }
+    }
+
+    // Write mixtures and flow vectors back.
+    for (entity, mut atmosphere, mut flow) in tiles.iter_mut() {
+        let new_mix = mixtures.remove(&entity);
+        if let Some(&dir) = flow_edges.get(&entity) {
+            // Magnitude is the pressure change the rush produced on this tile.
+            let before = atmosphere.mixture.pressure();
+            let after = new_mix.as_ref().map_or(before, |m| m.pressure());
+            flow.direction = direction_vector(dir);
+            flow.magnitude = before.abs_diff(after) as f32;
+        }
+        if let Some(mix) = new_mix {
+            atmosphere.mixture = mix;
+        }
+    }
+
+    // Re-queue tiles that still have (or just gained) a gradient. Tiles that
+    // reached equilibrium are simply not re-pushed; the dedup set keeps each tile
+    // queued at most once.
+    for entity in activate {
+        active_queue.push(entity);
+    }
+}
+
+/// Unit direction vector for a neighbor index `[North, East, South, West]`.
+fn direction_vector(index: usize) -> Vec2 {
+    match index {
+        0 => Vec2::new(0.0, 1.0),
+        1 => Vec2::new(1.0, 0.0),
+        2 => Vec2::new(0.0, -1.0),
+        3 => Vec2::new(-1.0, 0.0),
+        _ => Vec2::ZERO,
+    }
+}
+
+/// BFS-driven single-tick equalization of a connected pocket of open tiles.
+///
+/// Starting from the high-pressure `start`, we collect up to
+/// [`EXPLOSIVE_MAX_TILES`] connected open tiles, recording each tile's BFS parent
+/// edge. We then sum the moles of every species across the pocket and give each
+/// tile its volume-weighted share in one pass — or zero if the pocket reaches
+/// space: either a tile tagged [`ExposedToSpace`] or a near-vacuum tile (pressure
+/// at or below [`VACUUM_PRESSURE_MICRO_KPA`]), so a hull breach dumps the whole
+/// pocket outward at once.
+/// Each tile's dominant flow edge points back toward its parent, reflecting the
+/// direction of the rush.
+#[allow(clippy::too_many_arguments)]
+fn explosive_equalization(
+    start: Entity,
+    topology: &std::collections::HashMap<Entity, [Option<(Entity, bool)>; 4]>,
+    space_tiles: &std::collections::HashSet<Entity>,
+    mixtures: &mut std::collections::HashMap<Entity, GasMixture>,
+    flow_edges: &mut std::collections::HashMap<Entity, usize>,
+    activate: &mut std::collections::HashSet<Entity>,
+    consumed: &mut std::collections::HashSet<Entity>,
+) {
+    use std::collections::VecDeque;
+
+    // BFS collecting the pocket and each tile's parent edge (parent, dir-from-parent).
+    let mut order: Vec<Entity> = Vec::new();
+    let mut parent_dir: std::collections::HashMap<Entity, usize> = std::collections::HashMap::new();
+    let mut seen: std::collections::HashSet<Entity> = std::collections::HashSet::new();
+    let mut queue: VecDeque<Entity> = VecDeque::new();
+
+    // A tile counts as a space sink if it is explicitly tagged or is near-vacuum.
+    let is_space = |tile: &Entity| {
+        space_tiles.contains(tile)
+            || mixtures
+                .get(tile)
+                .is_some_and(|m| m.pressure() as i128 <= VACUUM_PRESSURE_MICRO_KPA)
+    };
+
+    seen.insert(start);
+    queue.push_back(start);
+    let mut touches_space = is_space(&start);
+
+    while let Some(tile) = queue.pop_front() {
+        order.push(tile);
+        if order.len() >= EXPLOSIVE_MAX_TILES {
+            break;
+        }
+        for (dir, neighbor_opt) in topology[&tile].iter().enumerate() {
+            if let Some((neighbor_entity, true)) = neighbor_opt {
+                let neighbor_entity = *neighbor_entity;
+                if seen.contains(&neighbor_entity) || !mixtures.contains_key(&neighbor_entity) {
+                    continue;
+                }
+                seen.insert(neighbor_entity);
+                parent_dir.insert(neighbor_entity, dir);
+                if is_space(&neighbor_entity) {
+                    touches_space = true;
+                }
+                queue.push_back(neighbor_entity);
+            }
+        }
+    }
+
+    let count = order.len() as u128;
+    if count == 0 {
+        return;
+    }
+
+    // Sum moles per species and total volume across the pocket.
+    let mut total_moles = [0u128; GAS_TYPE_COUNT];
+    let mut total_volume = 0u128;
+    for tile in &order {
+        let mix = &mixtures[tile];
+        total_volume += mix.volume as u128;
+        for gas in 0..GAS_TYPE_COUNT {
+            total_moles[gas] += mix.moles[gas] as u128;
+        }
+    }
+
+    // Assign each tile its volume-weighted target (zero if the pocket is open to
+    // space). Remainders accrue to the last tile so mass is exactly conserved.
+    let mut assigned = [0u128; GAS_TYPE_COUNT];
+    for (idx, tile) in order.iter().enumerate() {
+        let volume = mixtures[tile].volume as u128;
+        let is_last = idx + 1 == order.len();
+        for gas in 0..GAS_TYPE_COUNT {
+            let target = if touches_space {
+                0
+            } else if total_volume > 0 {
+                let share = total_moles[gas] * volume / total_volume;
+                if is_last {
+                    total_moles[gas] - assigned[gas]
+                } else {
+                    assigned[gas] += share;
+                    share
+                }
+            } else {
+                0
+            };
+            mixtures.get_mut(tile).unwrap().moles[gas] = target as u64;
+        }
+        // Flow points back toward the parent edge (the direction gas rushed from).
+        if let Some(&dir) = parent_dir.get(tile) {
+            flow_edges.insert(*tile, opposite_direction(dir));
+        }
+    }
+
+    // Wake open border neighbors that were not part of the pocket so flow
+    // continues outward past the cap.
+    let in_pocket: std::collections::HashSet<Entity> = order.iter().copied().collect();
+    for tile in &order {
+        for neighbor_opt in topology[tile].iter() {
+            if let Some((neighbor_entity, true)) = neighbor_opt {
+                if !in_pocket.contains(neighbor_entity) && mixtures.contains_key(neighbor_entity) {
+                    activate.insert(*neighbor_entity);
+                }
+            }
+        }
+        consumed.insert(*tile);
+    }
+}
+
+/// Opposite cardinal direction for a neighbor index `[N, E, S, W]`.
+fn opposite_direction(index: usize) -> usize {
+    match index {
+        0 => 2,
+        1 => 3,
+        2 => 0,
+        3 => 1,
+        _ => index,
+    }
+}
+
+/// Whole-space equalization pass in the spirit of auxmos's katmos.
+///
+/// Where [`process_gas_sharing`] crawls a gradient one edge per tick — visible as
+/// a slow pressure wave across a large room — this collapses a connected pocket
+/// in a single tick. From each active tile we flood-fill across open
+/// (non-[`Wall`]) neighbors whose pressure is within
+/// [`FLOOD_PRESSURE_THRESHOLD_MICRO_KPA`] of the seed, collecting up to
+/// [`FLOOD_MAX_TILES`] tiles, then sum the moles of every species and the total
+/// thermal energy across the group and redistribute them uniformly: each tile
+/// ends at the average mole vector and the energy-conserving equilibrium
+/// temperature. The group's open border tiles are re-queued so the turbulent
+/// boundary keeps flowing through the ordinary pairwise path.
+///
+/// This is the sole *per-tick* whole-room equalizer: it runs before
+/// [`process_gas_sharing`], reads the active set and settles the calm interiors
+/// ahead of it. The zone layer ([`apply_zone_mixtures`]) only equalizes once, on
+/// the tick a connectivity change rebuilds it — so the two no longer both own
+/// instant room equalization every frame. On that rebuild tick the zone scatter
+/// has already settled every room, so this pass skips (and clears the flag)
+/// rather than redundantly re-equalizing.
+pub fn process_flood_equalization(
+    mut tiles: Query<(Entity, &mut TileAtmosphere)>,
+    mut active_queue: ResMut<ActiveTileQueue>,
+    mut zones: ResMut<Zones>,
+) {
+    use std::collections::VecDeque;
+
+    // The zone layer just seeded every room with its equilibrium this tick;
+    // defer to it and avoid a second, competing equalization pass.
+    if zones.just_rebuilt() {
+        zones.clear_rebuilt();
+        return;
+    }
+
+    // Snapshot mixtures and topology; compute the whole redistribution before
+    // writing anything back, as the other multi-tile passes do.
+    let mut mixtures: std::collections::HashMap<Entity, GasMixture> = std::collections::HashMap::new();
+    let mut topology: std::collections::HashMap<Entity, [Option<(Entity, bool)>; 4]> =
+        std::collections::HashMap::new();
+    for (entity, atmosphere) in tiles.iter() {
+        mixtures.insert(entity, atmosphere.mixture.clone());
+        topology.insert(entity, atmosphere.neighbors);
+    }
+
+    let mut settled: std::collections::HashSet<Entity> = std::collections::HashSet::new();
+    let mut activate: std::collections::HashSet<Entity> = std::collections::HashSet::new();
+
+    for seed in active_queue.pending() {
+        if settled.contains(&seed) || !mixtures.contains_key(&seed) {
+            continue;
+        }
+        let seed_pressure = mixtures[&seed].pressure() as i128;
+
+        // Flood-fill a connected pocket of similar-pressure open tiles.
+        let mut order: Vec<Entity> = Vec::new();
+        let mut seen: std::collections::HashSet<Entity> = std::collections::HashSet::new();
+        let mut queue: VecDeque<Entity> = VecDeque::new();
+        seen.insert(seed);
+        queue.push_back(seed);
+
+        while let Some(tile) = queue.pop_front() {
+            order.push(tile);
+            if order.len() >= FLOOD_MAX_TILES {
+                break;
+            }
+            for neighbor_opt in topology[&tile].iter() {
+                if let Some((neighbor_entity, true)) = neighbor_opt {
+                    let neighbor_entity = *neighbor_entity;
+                    if seen.contains(&neighbor_entity) || settled.contains(&neighbor_entity) {
+                        continue;
                     }
-                    // If not found there, try active_tiles
-                    else if let Ok((_, neighbor_atmos)) = active_tiles.get(*neighbor_entity) {
-                        let neighbor_pressure = neighbor_atmos.mixture.pressure();
-                        let pressure_diff = (my_pressure as i128 - neighbor_pressure as i128).abs();
-                        
-                        if pressure_diff > 100_000 {
-                            has_active_exchange = true;
-                            neighbor_data.push((*neighbor_entity, neighbor_atmos.mixture.clone(), true));
-                        } else {
-                            neighbor_data.push((*neighbor_entity, neighbor_atmos.mixture.clone(), false));
-                        }
+                    let Some(neighbor_mix) = mixtures.get(&neighbor_entity) else {
+                        continue;
+                    };
+                    if (neighbor_mix.pressure() as i128 - seed_pressure).abs()
+                        <= FLOOD_PRESSURE_THRESHOLD_MICRO_KPA
+                    {
+                        seen.insert(neighbor_entity);
+                        queue.push_back(neighbor_entity);
                     }
                 }
             }
         }
-        
-        if !neighbor_data.is_empty() {
-            updates.push((entity, atmosphere.mixture.clone(), neighbor_data, has_active_exchange));
-        } else {
-            // No neighbors, can't be active
-            updates.push((entity, atmosphere.mixture.clone(), vec![], false));
+
+        // A lone tile has nothing to equalize against.
+        if order.len() < 2 {
+            settled.insert(seed);
+            continue;
+        }
+
+        // Sum moles per species and the total thermal energy across the pocket.
+        let count = order.len() as u128;
+        let mut total_moles = [0u128; GAS_TYPE_COUNT];
+        let mut total_energy = 0u128;
+        for tile in &order {
+            let mix = &mixtures[tile];
+            total_energy += mix.thermal_energy();
+            for gas in 0..GAS_TYPE_COUNT {
+                total_moles[gas] += mix.moles[gas] as u128;
+            }
+        }
+
+        // Redistribute uniformly: every tile gets the average mole vector, with
+        // the remainder parked on the last tile so mass is exactly conserved.
+        let mut assigned = [0u128; GAS_TYPE_COUNT];
+        for (idx, tile) in order.iter().enumerate() {
+            let is_last = idx + 1 == order.len();
+            let mix = mixtures.get_mut(tile).unwrap();
+            for gas in 0..GAS_TYPE_COUNT {
+                let share = if is_last {
+                    total_moles[gas] - assigned[gas]
+                } else {
+                    let s = total_moles[gas] / count;
+                    assigned[gas] += s;
+                    s
+                };
+                mix.moles[gas] = share as u64;
+            }
+            settled.insert(*tile);
+        }
+
+        // Energy-conserving equilibrium temperature: the summed capacity equals
+        // the capacity of the summed moles, so one pass sets every tile to it.
+        let total_capacity: u128 = order.iter().map(|t| mixtures[t].heat_capacity() as u128).sum();
+        if total_capacity > 0 {
+            let equilibrium = (total_energy / total_capacity).max(1) as u64;
+            for tile in &order {
+                mixtures.get_mut(tile).unwrap().temperature = equilibrium;
+            }
+        }
+
+        // Wake the open border so the pairwise path carries flow past the cap.
+        let in_group: std::collections::HashSet<Entity> = order.iter().copied().collect();
+        for tile in &order {
+            for neighbor_opt in topology[tile].iter() {
+                if let Some((neighbor_entity, true)) = neighbor_opt {
+                    if !in_group.contains(neighbor_entity) {
+                        activate.insert(*neighbor_entity);
+                    }
+                }
+            }
         }
     }
-    
-    // Process gas sharing for each active tile
-    for (tile_entity, mut tile_mixture, neighbor_data, has_active_exchange) in updates {
-        for (neighbor_entity, mut neighbor_mixture, had_pressure_diff) in neighbor_data {
-            if had_pressure_diff {
-                // Share gas between the two mixtures
-                tile_mixture.share_gas_with(&mut neighbor_mixture);
-                
-                // Update the neighbor's mixture
-                // First try other_tiles
-                if let Ok(mut neighbor_atmos) = other_tiles.get_mut(neighbor_entity) {
-                    neighbor_atmos.mixture = neighbor_mixture;
+
+    // Write settled mixtures back.
+    for (entity, mut atmosphere) in tiles.iter_mut() {
+        if settled.contains(&entity) {
+            if let Some(mix) = mixtures.remove(&entity) {
+                atmosphere.mixture = mix;
+            }
+        }
+    }
+    for entity in activate {
+        active_queue.push(entity);
+    }
+}
+
+/// Per-edge pressure threshold below which the parallel path ignores a gradient.
+const PARALLEL_SHARE_THRESHOLD: i128 = 100_000;
+
+/// Parallel, lock-free variant of [`process_gas_sharing`] for large maps.
+///
+/// Enabled by [`AtmosphereConfig::parallel`]. A snapshot of every tile's moles
+/// and pressure is taken once, then a [`ParallelSlice::par_chunk_map`] pass
+/// computes the net mole flux across each open edge and folds it into per-tile
+/// [`AtomicI64`] accumulators: because each edge's flux is subtracted from one
+/// side and added to the other with atomic `fetch_add`, total moles are conserved
+/// regardless of how the worker tasks interleave. A final sequential pass applies
+/// and clears the deltas. Heat transport is left to [`process_heat_conduction`].
+pub fn process_gas_sharing_parallel(
+    mut tiles: Query<(Entity, &mut TileAtmosphere)>,
+    config: Res<AtmosphereConfig>,
+    mut active_queue: ResMut<ActiveTileQueue>,
+) {
+    // Dense snapshot: one row per tile, neighbors resolved to row indices.
+    struct Row {
+        moles: [u64; GAS_TYPE_COUNT],
+        volume: u64,
+        temperature: u64,
+        pressure: i128,
+        neighbors: [Option<usize>; 4],
+    }
+
+    let entities: Vec<Entity> = tiles.iter().map(|(e, _)| e).collect();
+    let index: std::collections::HashMap<Entity, usize> =
+        entities.iter().enumerate().map(|(i, &e)| (e, i)).collect();
+
+    let mut rows: Vec<Row> = Vec::with_capacity(entities.len());
+    for (_, atmosphere) in tiles.iter() {
+        let mut neighbors = [None; 4];
+        for (dir, neighbor_opt) in atmosphere.neighbors.iter().enumerate() {
+            if let Some((neighbor_entity, true)) = neighbor_opt {
+                neighbors[dir] = index.get(neighbor_entity).copied();
+            }
+        }
+        rows.push(Row {
+            moles: atmosphere.mixture.moles,
+            volume: atmosphere.mixture.volume,
+            temperature: atmosphere.mixture.temperature,
+            pressure: atmosphere.mixture.pressure() as i128,
+            neighbors,
+        });
+    }
+
+    // Per-tile atomic mole deltas, shared immutably across worker tasks.
+    let deltas: Vec<[AtomicI64; GAS_TYPE_COUNT]> = (0..rows.len())
+        .map(|_| std::array::from_fn(|_| AtomicI64::new(0)))
+        .collect();
+
+    // Indices laid out contiguously so we can hand chunks to the task pool.
+    let work: Vec<usize> = (0..rows.len()).collect();
+    let rows_ref = &rows;
+    let deltas_ref = &deltas;
+    work.par_chunk_map(ComputeTaskPool::get(), config.chunk_size.max(1), |_, chunk| {
+        for &i in chunk {
+            let row = &rows_ref[i];
+            for neighbor in row.neighbors.iter().flatten() {
+                let j = *neighbor;
+                // Process every edge exactly once.
+                if j <= i {
+                    continue;
+                }
+                let other = &rows_ref[j];
+                let diff = row.pressure - other.pressure;
+                if diff.abs() < PARALLEL_SHARE_THRESHOLD {
+                    continue;
+                }
+
+                // Gas flows from the higher-pressure tile (`src`) to `dst`.
+                let (src, dst, src_idx, dst_idx) = if diff > 0 {
+                    (row, other, i, j)
+                } else {
+                    (other, row, j, i)
+                };
+                let src_total: u64 = src.moles.iter().sum();
+                if src_total == 0 {
+                    continue;
+                }
+
+                // Same transfer law as GasMixture::share_gas_with, per edge.
+                let transfer = (diff.abs() * src.volume as i128)
+                    / (8314 * (src.temperature.max(1) as i128) / 100);
+                let max_transfer = (src_total as i128 / 10).max(1);
+                let transfer = transfer.min(max_transfer);
+                if transfer == 0 {
+                    continue;
                 }
-                // If not there, try active_tiles
-                else if let Ok((_, mut neighbor_atmos)) = active_tiles.get_mut(neighbor_entity) {
-                    neighbor_atmos.mixture = neighbor_mixture;
+
+                for g in 0..GAS_TYPE_COUNT {
+                    if src.moles[g] == 0 {
+                        continue;
+                    }
+                    let moved = (transfer * src.moles[g] as i128 / src_total as i128) as i64;
+                    if moved == 0 {
+                        continue;
+                    }
+                    deltas_ref[src_idx][g].fetch_add(-moved, Ordering::Relaxed);
+                    deltas_ref[dst_idx][g].fetch_add(moved, Ordering::Relaxed);
                 }
-                
-                // ALWAYS mark neighbor as active when there's a pressure difference
-                // This ensures gas continues to spread outward
-                commands.entity(neighbor_entity).insert(AtmosphereActive);
             }
         }
-        
-        // Update the tile's mixture
-        if let Ok((_, mut tile_atmos)) = active_tiles.get_mut(tile_entity) {
-            tile_atmos.mixture = tile_mixture;
+    });
+
+    // Apply and clear: write the accumulated deltas back and wake changed tiles.
+    for (entity, mut atmosphere) in tiles.iter_mut() {
+        let Some(&i) = index.get(&entity) else {
+            continue;
+        };
+        let mut changed = false;
+        for g in 0..GAS_TYPE_COUNT {
+            let delta = deltas[i][g].load(Ordering::Relaxed);
+            if delta == 0 {
+                continue;
+            }
+            changed = true;
+            let updated = (atmosphere.mixture.moles[g] as i64 + delta).max(0) as u64;
+            atmosphere.mixture.moles[g] = updated;
         }
-        
-        // Remove active marker if no active exchange with any neighbor
-        if !has_active_exchange {
-            commands.entity(tile_entity).remove::<AtmosphereActive>();
+        if changed {
+            active_queue.push(entity);
         }
     }
 }
 
 /// System to initialize neighbor connections
 pub fn initialize_neighbors(
-    mut query: Query<(&TilePosition, &mut TileAtmosphere), Added<TileAtmosphere>>,
+    mut query: Query<(Entity, &TilePosition, &mut TileAtmosphere), Added<TileAtmosphere>>,
     tile_query: Query<(Entity, &TilePosition, Option<&Wall>)>,
+    mut active_queue: ResMut<ActiveTileQueue>,
 ) {
     // Build a position-to-entity map
     let mut position_map = std::collections::HashMap::new();
@@ -109,9 +577,9 @@ pub fn initialize_neighbors(
     }
     
     // Set up neighbor connections for newly added tiles
-    for (pos, mut atmosphere) in query.iter_mut() {
+    for (entity, pos, mut atmosphere) in query.iter_mut() {
         let neighbor_positions = pos.neighbors();
-        
+
         for (i, neighbor_pos) in neighbor_positions.iter().enumerate() {
             if let Some(&neighbor_entity) = position_map.get(neighbor_pos) {
                 // Neighbor is open (not sealed) if neither this tile nor the neighbor is a wall
@@ -119,6 +587,12 @@ pub fn initialize_neighbors(
                 atmosphere.neighbors[i] = Some((neighbor_entity, is_open));
             }
         }
+
+        // Seed the work queue with every open tile so its initial gradients are
+        // resolved; settled tiles drop out after the first drain.
+        if !wall_positions.contains(pos) {
+            active_queue.push(entity);
+        }
     }
 }
 
@@ -128,30 +602,41 @@ pub fn update_wall_connections(
     mut changed_walls_removed: RemovedComponents<Wall>,
     mut all_tiles: Query<(&TilePosition, &mut TileAtmosphere, Option<&Wall>)>,
     tile_lookup: Query<(Entity, &TilePosition, Option<&Wall>)>,
+    mut active_queue: ResMut<ActiveTileQueue>,
 ) {
-    // Check if any walls were added or removed
-    let has_added = !changed_walls_added.is_empty();
-    let has_removed = changed_walls_removed.read().next().is_some();
-    
-    if !has_added && !has_removed {
-        return;
-    }
-    
+    // Build the set of positions whose connectivity just changed.
+    let mut changed_positions: std::collections::HashSet<TilePosition> =
+        changed_walls_added.iter().copied().collect();
+
     // Build a position-to-entity map
     let mut position_map = std::collections::HashMap::new();
     let mut wall_positions = std::collections::HashSet::new();
-    
+    let mut position_of: std::collections::HashMap<Entity, TilePosition> =
+        std::collections::HashMap::new();
+
     for (entity, pos, wall) in tile_lookup.iter() {
         position_map.insert(*pos, entity);
+        position_of.insert(entity, *pos);
         if wall.is_some() {
             wall_positions.insert(*pos);
         }
     }
-    
+
+    // Removed walls report entities; resolve them back to positions.
+    for entity in changed_walls_removed.read() {
+        if let Some(pos) = position_of.get(&entity) {
+            changed_positions.insert(*pos);
+        }
+    }
+
+    if changed_positions.is_empty() {
+        return;
+    }
+
     // Update all tiles that might be affected
     for (pos, mut atmosphere, _wall) in all_tiles.iter_mut() {
         let neighbor_positions = pos.neighbors();
-        
+
         for (i, neighbor_pos) in neighbor_positions.iter().enumerate() {
             if let Some(&neighbor_entity) = position_map.get(neighbor_pos) {
                 // Neighbor is open if neither this tile nor the neighbor is a wall
@@ -160,19 +645,464 @@ pub fn update_wall_connections(
             }
         }
     }
+
+    // Wake the changed tiles and their open neighbors so the new gradients are
+    // resolved — a wall removal exposes a fresh pressure difference.
+    for pos in &changed_positions {
+        for probe in std::iter::once(*pos).chain(pos.neighbors()) {
+            if wall_positions.contains(&probe) {
+                continue;
+            }
+            if let Some(&entity) = position_map.get(&probe) {
+                active_queue.push(entity);
+            }
+        }
+    }
+}
+
+/// System to drive active atmospheric machinery (filters and pumps).
+///
+/// Each [`GasDevice`] addresses its input/output tiles by neighbor direction,
+/// moving a bounded number of moles per tick against a pressure limit. A trinary
+/// filter splits the `filter_type` species into one output and the remainder into
+/// the other; a pump transfers gas toward a target output pressure. Every tile a
+/// device touches is pushed onto the work queue so downstream diffusion responds.
+pub fn process_gas_devices(
+    mut tiles: Query<(Entity, &mut TileAtmosphere)>,
+    devices: Query<(Entity, &GasDevice)>,
+    mut active_queue: ResMut<ActiveTileQueue>,
+) {
+    // Snapshot mixtures and topology so multi-tile transfers can be computed
+    // before any write-back, matching the gas-sharing collect/apply pattern.
+    let mut mixtures: std::collections::HashMap<Entity, GasMixture> = std::collections::HashMap::new();
+    let mut neighbors: std::collections::HashMap<Entity, [Option<(Entity, bool)>; 4]> =
+        std::collections::HashMap::new();
+    for (entity, atmosphere) in tiles.iter() {
+        mixtures.insert(entity, atmosphere.mixture.clone());
+        neighbors.insert(entity, atmosphere.neighbors);
+    }
+
+    let mut touched: std::collections::HashSet<Entity> = std::collections::HashSet::new();
+
+    for (device_entity, device) in devices.iter() {
+        let Some(device_neighbors) = neighbors.get(&device_entity).copied() else {
+            continue;
+        };
+        let resolve = |dir: usize| device_neighbors[dir].map(|(e, _)| e);
+
+        match &device.kind {
+            DeviceKind::Filter {
+                input,
+                filtered_output,
+                unfiltered_output,
+                filter_type,
+                max_moles,
+                max_output_pressure,
+            } => {
+                let (Some(input_e), Some(filtered_e), Some(unfiltered_e)) =
+                    (resolve(*input), resolve(*filtered_output), resolve(*unfiltered_output))
+                else {
+                    continue;
+                };
+                // Distinct tiles so we can hold each side out of the map at once.
+                if input_e == filtered_e || input_e == unfiltered_e || filtered_e == unfiltered_e {
+                    continue;
+                }
+
+                let available = mixtures[&input_e].total_moles();
+                if available == 0 {
+                    continue;
+                }
+                let budget = (*max_moles).min(available);
+
+                let mut src = mixtures.remove(&input_e).unwrap();
+                let mut filtered = mixtures.remove(&filtered_e).unwrap();
+                let mut unfiltered = mixtures.remove(&unfiltered_e).unwrap();
+                for gas in GasType::ALL {
+                    let present = src.get_moles(gas);
+                    if present == 0 {
+                        continue;
+                    }
+                    // Proportional slice of this tick's budget for this species.
+                    let moved = ((present as u128 * budget as u128) / available as u128) as u64;
+                    if moved == 0 {
+                        continue;
+                    }
+                    let dest = if filter_type.contains(&gas) {
+                        &mut filtered
+                    } else {
+                        &mut unfiltered
+                    };
+                    if dest.pressure() >= *max_output_pressure {
+                        continue; // Output full — leave the gas in the input.
+                    }
+                    // Carry heat with the moved gas so energy stays consistent.
+                    src.transfer_to(dest, gas, moved);
+                }
+                mixtures.insert(input_e, src);
+                mixtures.insert(filtered_e, filtered);
+                mixtures.insert(unfiltered_e, unfiltered);
+                touched.insert(input_e);
+                touched.insert(filtered_e);
+                touched.insert(unfiltered_e);
+            }
+            DeviceKind::Pump {
+                input,
+                output,
+                max_moles,
+                target_pressure,
+            } => {
+                let (Some(input_e), Some(output_e)) = (resolve(*input), resolve(*output)) else {
+                    continue;
+                };
+                if input_e == output_e || mixtures[&output_e].pressure() >= *target_pressure {
+                    continue;
+                }
+                let available = mixtures[&input_e].total_moles();
+                if available == 0 {
+                    continue;
+                }
+                let budget = (*max_moles).min(available);
+                let mut src = mixtures.remove(&input_e).unwrap();
+                let mut dst = mixtures.remove(&output_e).unwrap();
+                for gas in GasType::ALL {
+                    let present = src.get_moles(gas);
+                    if present == 0 {
+                        continue;
+                    }
+                    let moved = ((present as u128 * budget as u128) / available as u128) as u64;
+                    if moved == 0 {
+                        continue;
+                    }
+                    src.transfer_to(&mut dst, gas, moved);
+                }
+                mixtures.insert(input_e, src);
+                mixtures.insert(output_e, dst);
+                touched.insert(input_e);
+                touched.insert(output_e);
+            }
+            DeviceKind::Vent {
+                tile,
+                network,
+                max_moles,
+            } => {
+                let Some(tile_e) = resolve(*tile) else {
+                    continue;
+                };
+                let tile_pressure = mixtures[&tile_e].pressure() as i128;
+                let network_pressure = network.pressure() as i128;
+                if (tile_pressure - network_pressure).abs() < 100_000 {
+                    continue; // Already balanced with the network.
+                }
+
+                let tile_mix = mixtures.get_mut(&tile_e).unwrap();
+                if tile_pressure > network_pressure {
+                    // Overpressure — scrub the room's gas out into the network.
+                    let available = tile_mix.total_moles();
+                    if available == 0 {
+                        continue;
+                    }
+                    let budget = (*max_moles).min(available);
+                    let mut sink = network.clone();
+                    for gas in GasType::ALL {
+                        let present = tile_mix.get_moles(gas);
+                        if present == 0 {
+                            continue;
+                        }
+                        let moved = ((present as u128 * budget as u128) / available as u128) as u64;
+                        tile_mix.transfer_to(&mut sink, gas, moved);
+                    }
+                } else {
+                    // Underpressure — supply the network's gas into the room.
+                    let available = network.total_moles();
+                    if available == 0 {
+                        continue;
+                    }
+                    let budget = (*max_moles).min(available);
+                    let mut source = network.clone();
+                    for gas in GasType::ALL {
+                        let present = source.get_moles(gas);
+                        if present == 0 {
+                            continue;
+                        }
+                        let moved = ((present as u128 * budget as u128) / available as u128) as u64;
+                        source.transfer_to(tile_mix, gas, moved);
+                    }
+                }
+                touched.insert(tile_e);
+            }
+        }
+    }
+
+    // Write back mutated mixtures and wake every touched tile.
+    for (entity, mut atmosphere) in tiles.iter_mut() {
+        if touched.contains(&entity) {
+            if let Some(mix) = mixtures.remove(&entity) {
+                atmosphere.mixture = mix;
+            }
+        }
+    }
+    for entity in touched {
+        active_queue.push(entity);
+    }
+}
+
+/// System to conduct heat between adjacent tiles, independent of gas flow.
+///
+/// Unlike [`process_gas_sharing`], conduction crosses *every* adjacency —
+/// including sealed ones — so a [`Wall`] carrying a [`ThermalConductor`] slowly
+/// equalizes temperature across a barrier and heats up itself. Each undirected
+/// edge relaxes both sides toward their heat-capacity-weighted equilibrium
+/// temperature, conserving thermal energy; the exchange rate is the lower of the
+/// two sides' conductivities. Tiles whose temperature changed are re-queued so
+/// pressure and reactions pick up the new heat.
+pub fn process_heat_conduction(
+    mut tiles: Query<(
+        Entity,
+        &mut TileAtmosphere,
+        Option<&mut ThermalConductor>,
+        Option<&Wall>,
+    )>,
+    mut active_queue: ResMut<ActiveTileQueue>,
+) {
+    // Snapshot each tile's thermal node: (temperature, heat_capacity, conductivity).
+    struct Node {
+        temperature: i128,
+        capacity: i128,
+        conductivity: i128,
+        neighbors: [Option<(Entity, bool)>; 4],
+    }
+
+    let mut nodes: std::collections::HashMap<Entity, Node> = std::collections::HashMap::new();
+    for (entity, atmosphere, conductor, _wall) in tiles.iter() {
+        let node = if let Some(conductor) = conductor {
+            Node {
+                temperature: conductor.temperature as i128,
+                capacity: conductor.heat_capacity as i128,
+                conductivity: conductor.conductivity as i128,
+                neighbors: atmosphere.neighbors,
+            }
+        } else {
+            Node {
+                temperature: atmosphere.mixture.temperature as i128,
+                capacity: atmosphere.mixture.heat_capacity() as i128,
+                conductivity: GAS_THERMAL_CONDUCTIVITY as i128,
+                neighbors: atmosphere.neighbors,
+            }
+        };
+        nodes.insert(entity, node);
+    }
+
+    // Accumulate temperature deltas over each undirected adjacency.
+    let mut deltas: std::collections::HashMap<Entity, i128> = std::collections::HashMap::new();
+    for (&entity, node) in nodes.iter() {
+        for neighbor_opt in node.neighbors.iter() {
+            let Some((neighbor, _is_open)) = *neighbor_opt else {
+                continue;
+            };
+            // Process each pair once, ordered by entity bits.
+            if entity.to_bits() >= neighbor.to_bits() {
+                continue;
+            }
+            let Some(other) = nodes.get(&neighbor) else {
+                continue;
+            };
+
+            let cap_sum = node.capacity + other.capacity;
+            if cap_sum == 0 {
+                continue;
+            }
+            let conductivity = node.conductivity.min(other.conductivity);
+            if conductivity == 0 {
+                continue;
+            }
+            // Heat-capacity-weighted equilibrium; relax both sides toward it.
+            let equilibrium =
+                (node.temperature * node.capacity + other.temperature * other.capacity) / cap_sum;
+            let delta_a = (equilibrium - node.temperature) * conductivity / 1000;
+            let delta_b = (equilibrium - other.temperature) * conductivity / 1000;
+            *deltas.entry(entity).or_default() += delta_a;
+            *deltas.entry(neighbor).or_default() += delta_b;
+        }
+    }
+
+    // Apply deltas back to the gas mixture or the solid conductor, and wake tiles
+    // whose temperature actually moved.
+    for (entity, mut atmosphere, conductor, _wall) in tiles.iter_mut() {
+        let Some(delta) = deltas.get(&entity).copied() else {
+            continue;
+        };
+        if delta == 0 {
+            continue;
+        }
+        if let Some(mut conductor) = conductor {
+            conductor.temperature = (conductor.temperature as i128 + delta).max(1) as u64;
+        } else {
+            atmosphere.mixture.temperature =
+                (atmosphere.mixture.temperature as i128 + delta).max(1) as u64;
+            active_queue.push(entity);
+        }
+    }
+}
+
+/// System to run gas-phase chemistry over active tiles after gas sharing.
+///
+/// Each active tile's mixture is run through the [`ReactionRegistry`]; if a
+/// reaction changed the tile's pressure, the tile is re-pushed onto the
+/// [`ActiveTileQueue`] so the resulting pressure wave keeps spreading, and a
+/// [`TileIgnited`] event fires when an exothermic burn releases energy — which
+/// is how fire propagates through connected tiles.
+pub fn process_reactions(
+    mut tiles: Query<(Entity, &mut TileAtmosphere)>,
+    registry: Res<ReactionRegistry>,
+    mut active_queue: ResMut<ActiveTileQueue>,
+    mut ignitions: EventWriter<TileIgnited>,
+) {
+    // Seed from the active queue plus any tile whose composition and temperature
+    // are independently ignitable. Gas sharing drains the queue down to tiles
+    // with a surviving pressure gradient, so a uniform plasma+O₂ pocket heated
+    // past ignition would never be queued and would never light without this.
+    let mut candidates: std::collections::HashSet<Entity> =
+        active_queue.pending().into_iter().collect();
+    for (entity, atmosphere) in tiles.iter() {
+        if registry.can_react(&atmosphere.mixture) {
+            candidates.insert(entity);
+        }
+    }
+
+    for entity in candidates {
+        let Ok((_, mut atmosphere)) = tiles.get_mut(entity) else {
+            continue;
+        };
+        let before = atmosphere.mixture.pressure();
+        let result = registry.react(&mut atmosphere.mixture);
+        if !result.changed {
+            continue;
+        }
+        // A reaction that moved pressure keeps the tile (and its neighbors,
+        // reached via diffusion) active so fire propagates.
+        if atmosphere.mixture.pressure() != before {
+            active_queue.push(entity);
+        }
+        if result.ignited {
+            ignitions.send(TileIgnited { tile: entity });
+        }
+    }
+}
+
+/// System to (re)build the zone layer with union-find over open neighbor edges.
+///
+/// Runs on startup (when any tile is freshly added) and whenever a wall change
+/// alters connectivity, so each connected component of open tiles collapses to a
+/// single shared [`super::zones::Zone`] mixture. Tiles then read their pressure
+/// and composition from that mixture, letting a sealed room equilibrate in one
+/// tick instead of crawling a trail of active tiles across it edge by edge.
+pub fn rebuild_zones(
+    tiles: Query<(Entity, &TileAtmosphere, Option<&Wall>, Option<&Door>)>,
+    walls_added: Query<(), Added<Wall>>,
+    mut walls_removed: RemovedComponents<Wall>,
+    doors_added: Query<(), Added<Door>>,
+    mut doors_removed: RemovedComponents<Door>,
+    new_tiles: Query<(), Added<TileAtmosphere>>,
+    mut zones: ResMut<Zones>,
+) {
+    let connectivity_changed = !walls_added.is_empty()
+        || walls_removed.read().next().is_some()
+        || !doors_added.is_empty()
+        || doors_removed.read().next().is_some()
+        || !new_tiles.is_empty();
+    if !connectivity_changed {
+        return;
+    }
+
+    let snapshots: Vec<TileSnapshot> = tiles
+        .iter()
+        .map(|(entity, atmosphere, wall, door)| TileSnapshot {
+            entity,
+            neighbors: atmosphere.neighbors,
+            mixture: atmosphere.mixture.clone(),
+            open: wall.is_none(),
+            door: door.is_some(),
+        })
+        .collect();
+
+    zones.rebuild(&snapshots);
+}
+
+/// System to keep inter-zone door tiles active so gas keeps crossing partial
+/// barriers through the slower pairwise path.
+///
+/// Zones joined by a [`super::zones::ZoneEdge`] are deliberately not merged, so
+/// their interiors settle independently and would drop out of the work queue. By
+/// re-pushing each edge's door tile every tick, [`process_gas_sharing`] keeps
+/// running [`GasMixture::share_gas_with`] across the door until the two rooms
+/// actually equalize — the realistic slow bleed a closed door should allow.
+pub fn process_zone_edges(zones: Res<Zones>, mut active_queue: ResMut<ActiveTileQueue>) {
+    for edge in zones.edges() {
+        active_queue.push(edge.door);
+    }
+}
+
+/// System to tag each tile with its current zone and scatter the shared zone
+/// mixture back onto member tiles, apportioning moles by tile volume.
+///
+/// This only fires on the tick [`rebuild_zones`] recomputed the zone layer after
+/// a connectivity change — it seeds every member tile with the instant
+/// equilibrium of its room. On subsequent ticks the per-tile simulation systems
+/// (sharing, reactions, devices, breathing, conduction) own [`TileAtmosphere::mixture`];
+/// re-scattering the stale zone snapshot every frame would discard their work and
+/// freeze the grid at the initial equilibrium.
+pub fn apply_zone_mixtures(
+    mut tiles: Query<(Entity, &mut TileAtmosphere)>,
+    mut zones: ResMut<Zones>,
+    mut commands: Commands,
+) {
+    if !zones.just_rebuilt() {
+        return;
+    }
+
+    for (entity, mut atmosphere) in tiles.iter_mut() {
+        match zones.zone_of(entity) {
+            Some(id) => {
+                if let Some(mixture) = zones.scatter_to(id, atmosphere.mixture.volume) {
+                    atmosphere.mixture = mixture;
+                }
+                commands.entity(entity).insert(ZoneMember(id));
+            }
+            None => {
+                commands.entity(entity).remove::<ZoneMember>();
+            }
+        }
+    }
+    // The rebuild flag is cleared by process_flood_equalization, which reads it to
+    // skip its own pass on the tick the zone layer already equalized every room.
 }
 
 /// System to update tile visual representation based on atmospheric pressure
 pub fn update_tile_visuals(
-    mut query: Query<(&TileAtmosphere, &mut Sprite, Option<&Wall>)>,
+    mut query: Query<(&TileAtmosphere, &mut Sprite, Option<&Wall>, Option<&ThermalConductor>)>,
+    mode: Res<VisualizationMode>,
 ) {
-    for (atmosphere, mut sprite, wall) in query.iter_mut() {
+    for (atmosphere, mut sprite, wall, conductor) in query.iter_mut() {
+        // Temperature overlay: tint every tile by temperature so hot tiles read
+        // red regardless of pressure. Walls use their own stored temperature.
+        if *mode == VisualizationMode::Temperature {
+            let temp_mk = conductor
+                .map(|c| c.temperature)
+                .unwrap_or(atmosphere.mixture.temperature);
+            let temp_k = temp_mk as f32 / 1000.0;
+            // 273 K reads blue, 473 K and above reads full red.
+            let t = ((temp_k - 273.0) / 200.0).clamp(0.0, 1.0);
+            sprite.color = Color::srgb(t, 0.1, 1.0 - t);
+            continue;
+        }
+
         // If it's a wall, color it gray
         if wall.is_some() {
             sprite.color = Color::srgb(0.4, 0.4, 0.4);
             continue;
         }
-        
+
         let pressure = atmosphere.mixture.pressure() as f32 / 1_000_000.0; // Convert to kPa
         let standard_pressure = 101.325;
         
@@ -0,0 +1,351 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use super::gas::{GasMixture, GAS_TYPE_COUNT};
+
+/// Identifier for an atmospheric zone.
+///
+/// A zone is a maximal set of mutually-connected open tiles that share a single
+/// [`GasMixture`]. Sealing or breaching a wall splits or merges zones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZoneId(pub u32);
+
+/// A zone owns one [`GasMixture`] shared by every member tile, so a sealed room
+/// reaches equilibrium instantly instead of diffusing tile-by-tile.
+pub struct Zone {
+    /// The mixture shared by all member tiles. Its `volume` is the sum of the
+    /// member tiles' volumes.
+    pub mixture: GasMixture,
+    /// Member tiles, in insertion order.
+    pub tiles: Vec<Entity>,
+}
+
+/// Component tagging a tile with the zone it currently belongs to.
+///
+/// Wall tiles have no zone; every other tile, vacuum included, belongs to one.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ZoneMember(pub ZoneId);
+
+/// An explicit connection between two zones through a partial barrier (a door or
+/// a small gap). Gas still crosses these edges through the slower
+/// [`GasMixture::share_gas_with`] path: the `door` tile sits open between the two
+/// zones, and [`super::systems::process_zone_edges`] keeps it active so the
+/// pairwise exchange across it never stalls once the rooms' interiors settle.
+#[derive(Debug, Clone, Copy)]
+pub struct ZoneEdge {
+    pub a: ZoneId,
+    pub b: ZoneId,
+    /// The partial-barrier tile bridging the two zones.
+    pub door: Entity,
+}
+
+/// Resource owning every zone and the tile→zone mapping.
+///
+/// Zones are rebuilt with a union-find pass over open [`super::components::TileAtmosphere::neighbors`]
+/// edges whenever wall connectivity changes.
+#[derive(Resource, Default)]
+pub struct Zones {
+    zones: HashMap<ZoneId, Zone>,
+    tile_zone: HashMap<Entity, ZoneId>,
+    edges: Vec<ZoneEdge>,
+    next_id: u32,
+    /// Set by [`Zones::rebuild`], cleared once the fresh equilibrium has been
+    /// scattered back onto member tiles. Lets the scatter run only on the tick a
+    /// connectivity change rebuilt the zones instead of overwriting every tile's
+    /// simulated mixture each frame.
+    rebuilt: bool,
+}
+
+/// Input row for a zone rebuild: the tile, its four open-neighbor edges, and its
+/// current mixture (used to seed the zone mixture and to redistribute on a split).
+pub struct TileSnapshot {
+    pub entity: Entity,
+    pub neighbors: [Option<(Entity, bool)>; 4],
+    pub mixture: GasMixture,
+    /// True if the tile participates in atmospherics (not a wall / not sealed).
+    pub open: bool,
+    /// True if the tile is a partial barrier (a [`super::components::Door`]): it
+    /// keeps the rooms it separates in distinct zones but still diffuses gas
+    /// across itself, so it yields a [`ZoneEdge`] rather than a zone merge.
+    pub door: bool,
+}
+
+/// Minimal disjoint-set over a dense index space, used to group connected tiles.
+struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+        }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Path compression.
+        let mut cur = x;
+        while self.parent[cur] != root {
+            let next = self.parent[cur];
+            self.parent[cur] = root;
+            cur = next;
+        }
+        root
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (ra, rb) = (self.find(a), self.find(b));
+        if ra == rb {
+            return;
+        }
+        if self.rank[ra] < self.rank[rb] {
+            self.parent[ra] = rb;
+        } else if self.rank[ra] > self.rank[rb] {
+            self.parent[rb] = ra;
+        } else {
+            self.parent[rb] = ra;
+            self.rank[ra] += 1;
+        }
+    }
+}
+
+impl Zones {
+    /// Look up the zone a tile belongs to, if any.
+    pub fn zone_of(&self, tile: Entity) -> Option<ZoneId> {
+        self.tile_zone.get(&tile).copied()
+    }
+
+    /// Borrow a zone's shared mixture.
+    pub fn mixture(&self, id: ZoneId) -> Option<&GasMixture> {
+        self.zones.get(&id).map(|z| &z.mixture)
+    }
+
+    /// Mutably borrow a zone's shared mixture (e.g. for reactions or devices).
+    pub fn mixture_mut(&mut self, id: ZoneId) -> Option<&mut GasMixture> {
+        self.zones.get_mut(&id).map(|z| &mut z.mixture)
+    }
+
+    /// Inter-zone openings that still diffuse through the pairwise path.
+    pub fn edges(&self) -> &[ZoneEdge] {
+        &self.edges
+    }
+
+    /// True if a rebuild has produced a fresh equilibrium that has not yet been
+    /// scattered back to member tiles.
+    pub fn just_rebuilt(&self) -> bool {
+        self.rebuilt
+    }
+
+    /// Clear the rebuild flag once the equilibrium has been applied to tiles.
+    pub fn clear_rebuilt(&mut self) {
+        self.rebuilt = false;
+    }
+
+    fn alloc_id(&mut self) -> ZoneId {
+        let id = ZoneId(self.next_id);
+        self.next_id = self.next_id.wrapping_add(1);
+        id
+    }
+
+    /// Rebuild every zone from scratch with union-find over open neighbor edges.
+    ///
+    /// Each connected component of open tiles becomes one zone whose mixture sums
+    /// the moles of its members (and whose volume sums their volumes), so member
+    /// tiles read a single equilibrium pressure. Used on initialization and on
+    /// every connectivity change; each call re-runs union-find over all tiles,
+    /// with no incremental update of just the components a change touched.
+    pub fn rebuild(&mut self, tiles: &[TileSnapshot]) {
+        self.zones.clear();
+        self.tile_zone.clear();
+        self.edges.clear();
+        self.rebuilt = true;
+
+        let index: HashMap<Entity, usize> =
+            tiles.iter().enumerate().map(|(i, t)| (t.entity, i)).collect();
+
+        let mut uf = UnionFind::new(tiles.len());
+        for (i, tile) in tiles.iter().enumerate() {
+            if !tile.open || tile.door {
+                // A door never merges the rooms it connects; it gets its own
+                // singleton zone and an explicit edge below.
+                continue;
+            }
+            for neighbor in tile.neighbors.iter().flatten() {
+                let (neighbor_entity, is_open) = *neighbor;
+                if !is_open {
+                    continue;
+                }
+                if let Some(&j) = index.get(&neighbor_entity) {
+                    if tiles[j].open && !tiles[j].door {
+                        uf.union(i, j);
+                    }
+                }
+            }
+        }
+
+        // Materialize one zone per component root.
+        let mut root_zone: HashMap<usize, ZoneId> = HashMap::new();
+        for (i, tile) in tiles.iter().enumerate() {
+            if !tile.open {
+                continue;
+            }
+            let root = uf.find(i);
+            let id = *root_zone.entry(root).or_insert_with(|| self.alloc_id());
+            let zone = self.zones.entry(id).or_insert_with(|| Zone {
+                mixture: GasMixture::new(0, tile.mixture.temperature),
+                tiles: Vec::new(),
+            });
+            zone.tiles.push(tile.entity);
+            merge_into(&mut zone.mixture, &tile.mixture);
+            self.tile_zone.insert(tile.entity, id);
+        }
+
+        // Record an edge for every pair of distinct zones a door connects, so the
+        // partial-barrier topology is explicit even though gas crosses the door
+        // through the per-tile share_gas_with path rather than a zone merge.
+        let mut seen_edges: std::collections::HashSet<(u32, u32)> = std::collections::HashSet::new();
+        for tile in tiles.iter() {
+            if !tile.open || !tile.door {
+                continue;
+            }
+            let mut adjacent: Vec<ZoneId> = Vec::new();
+            for neighbor in tile.neighbors.iter().flatten() {
+                let (neighbor_entity, is_open) = *neighbor;
+                if !is_open {
+                    continue;
+                }
+                if let Some(&zid) = self.tile_zone.get(&neighbor_entity) {
+                    if !adjacent.contains(&zid) {
+                        adjacent.push(zid);
+                    }
+                }
+            }
+            for a in 0..adjacent.len() {
+                for b in (a + 1)..adjacent.len() {
+                    let (za, zb) = (adjacent[a], adjacent[b]);
+                    let key = (za.0.min(zb.0), za.0.max(zb.0));
+                    if seen_edges.insert(key) {
+                        self.edges.push(ZoneEdge { a: za, b: zb, door: tile.entity });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Distribute a zone's shared mixture back to each member tile, apportioning
+    /// moles by the tile's share of the zone volume. Called after the zone mixture
+    /// changes so per-tile reads (visuals, flow, devices) see the equilibrium.
+    pub fn scatter_to(&self, id: ZoneId, tile_volume: u64) -> Option<GasMixture> {
+        let zone = self.zones.get(&id)?;
+        if zone.mixture.volume == 0 {
+            return Some(GasMixture::new(tile_volume, zone.mixture.temperature));
+        }
+        let mut out = GasMixture::new(tile_volume, zone.mixture.temperature);
+        for gas in 0..GAS_TYPE_COUNT {
+            out.moles[gas] = ((zone.mixture.moles[gas] as u128 * tile_volume as u128)
+                / zone.mixture.volume as u128) as u64;
+        }
+        Some(out)
+    }
+}
+
+/// Fold one tile mixture into an accumulating zone mixture, conserving moles and
+/// volume and taking the volume-weighted mean temperature.
+fn merge_into(zone: &mut GasMixture, tile: &GasMixture) {
+    let combined_volume = zone.volume as u128 + tile.volume as u128;
+    if combined_volume > 0 {
+        zone.temperature = ((zone.temperature as u128 * zone.volume as u128
+            + tile.temperature as u128 * tile.volume as u128)
+            / combined_volume) as u64;
+    }
+    zone.volume = combined_volume as u64;
+    for gas in 0..GAS_TYPE_COUNT {
+        zone.moles[gas] = zone.moles[gas].saturating_add(tile.moles[gas]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::atmosphere::gas::{GasType, STANDARD_TEMP_MK, STANDARD_VOLUME_MICRO_M3};
+
+    fn snapshot(entity: Entity, neighbors: [Option<(Entity, bool)>; 4], mixture: GasMixture) -> TileSnapshot {
+        TileSnapshot { entity, neighbors, mixture, open: true, door: false }
+    }
+
+    #[test]
+    fn connected_open_tiles_form_one_zone() {
+        let a = Entity::from_raw(0);
+        let b = Entity::from_raw(1);
+
+        let mut air = GasMixture::new(STANDARD_VOLUME_MICRO_M3, STANDARD_TEMP_MK);
+        air.add_moles(GasType::Oxygen, 1_000_000);
+
+        let tiles = vec![
+            snapshot(a, [Some((b, true)), None, None, None], air.clone()),
+            snapshot(b, [None, None, Some((a, true)), None], air.clone()),
+        ];
+
+        let mut zones = Zones::default();
+        zones.rebuild(&tiles);
+
+        let za = zones.zone_of(a).unwrap();
+        assert_eq!(Some(za), zones.zone_of(b));
+        // Zone mole count is the sum of member tiles.
+        assert_eq!(zones.mixture(za).unwrap().get_moles(GasType::Oxygen), 2_000_000);
+    }
+
+    #[test]
+    fn sealed_edge_splits_into_two_zones() {
+        let a = Entity::from_raw(0);
+        let b = Entity::from_raw(1);
+
+        let tiles = vec![
+            snapshot(a, [Some((b, false)), None, None, None], GasMixture::default()),
+            snapshot(b, [None, None, Some((a, false)), None], GasMixture::default()),
+        ];
+
+        let mut zones = Zones::default();
+        zones.rebuild(&tiles);
+
+        assert_ne!(zones.zone_of(a), zones.zone_of(b));
+    }
+
+    #[test]
+    fn door_keeps_rooms_separate_but_records_an_edge() {
+        // Two room tiles separated by a door tile: a—d—b.
+        let a = Entity::from_raw(0);
+        let d = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+
+        let tiles = vec![
+            snapshot(a, [Some((d, true)), None, None, None], GasMixture::default()),
+            TileSnapshot {
+                door: true,
+                ..snapshot(
+                    d,
+                    [Some((b, true)), None, Some((a, true)), None],
+                    GasMixture::default(),
+                )
+            },
+            snapshot(b, [None, None, Some((d, true)), None], GasMixture::default()),
+        ];
+
+        let mut zones = Zones::default();
+        zones.rebuild(&tiles);
+
+        // The door does not merge the two rooms.
+        assert_ne!(zones.zone_of(a), zones.zone_of(b));
+        // But it connects them through an explicit edge.
+        let (za, zb) = (zones.zone_of(a).unwrap(), zones.zone_of(b).unwrap());
+        assert!(zones.edges().iter().any(|e| {
+            (e.a == za && e.b == zb) || (e.a == zb && e.b == za)
+        }));
+    }
+}
+</content>
@@ -2,6 +2,7 @@ mod atmosphere;
 
 use bevy::prelude::*;
 use atmosphere::{AtmospherePlugin, components::*};
+use atmosphere::breathing::{BreathState, Breather};
 
 const TILE_SIZE: f32 = 32.0;
 const GRID_SIZE: i32 = 25;
@@ -12,12 +13,16 @@ struct FlowArrow {
     parent_tile: Entity,
 }
 
+/// Marker for the occupant sprite, tinted by its breathability each frame.
+#[derive(Component)]
+struct OccupantMarker;
+
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(AtmospherePlugin)
+        .add_plugins(AtmospherePlugin::default())
         .add_systems(Startup, setup)
-        .add_systems(Update, (handle_tile_click, visualize_flow_arrows))
+        .add_systems(Update, (handle_tile_click, visualize_flow_arrows, toggle_visualization_mode, update_occupant_marker))
         .run();
 }
 
@@ -26,19 +31,31 @@ fn setup(mut commands: Commands) {
     commands.spawn(Camera2d);
     
     let half_size = GRID_SIZE / 2;
-    
+    let mut center_tile: Option<Entity> = None;
+
     // Create a 25x25 grid
     for x in -half_size..=half_size {
         for y in -half_size..=half_size {
             let is_center = x == 0 && y == 0;
-            let is_wall_ring = (x.abs() == 1 || y.abs() == 1) && x.abs() <= 1 && y.abs() <= 1;
-            
-            let (atmosphere, has_wall) = if is_center {
-                // Center tile has air
+            // A second air room one tile north, joined to the center room by a
+            // door at (0, 1): the two rooms stay distinct zones linked by a
+            // ZoneEdge, and gas bleeds between them through the slow door path.
+            let is_room2 = x == 0 && y == 2;
+            let is_door = x == 0 && y == 1;
+            let is_center_ring =
+                !is_door && (x.abs() == 1 || y.abs() == 1) && x.abs() <= 1 && y.abs() <= 1;
+            let is_room2_wall = (x == 0 && y == 3) || (x == 1 && y == 2) || (x == -1 && y == 2);
+            let is_wall = is_center_ring || is_room2_wall;
+
+            let (atmosphere, has_wall) = if is_center || is_room2 {
+                // Both rooms start full of air.
                 (TileAtmosphere::new_with_air(), false)
-            } else if is_wall_ring {
-                // Ring around center is walls (with vacuum)
+            } else if is_wall {
+                // Walls enclosing the two rooms (with vacuum).
                 (TileAtmosphere::new_vacuum(), true)
+            } else if is_door {
+                // The door tile is open and starts balanced with the rooms.
+                (TileAtmosphere::new_with_air(), false)
             } else {
                 // Everything else is vacuum
                 (TileAtmosphere::new_vacuum(), false)
@@ -56,14 +73,21 @@ fn setup(mut commands: Commands) {
                 Transform::from_xyz(x as f32 * TILE_SIZE, y as f32 * TILE_SIZE, 0.0),
             )).id();
             
-            // Add wall component if this is a wall
+            // Add wall component if this is a wall. Open tiles are seeded into
+            // the active-tile work queue by initialize_neighbors. Walls also get a
+            // ThermalConductor so heat slowly crosses the sealed barrier.
             if has_wall {
-                commands.entity(tile_entity).insert(Wall);
-            } else if is_center {
-                // Mark center as active to start gas flow
-                commands.entity(tile_entity).insert(AtmosphereActive);
+                commands.entity(tile_entity).insert((Wall, ThermalConductor::default()));
             }
-            
+            if is_door {
+                // Partial barrier: keeps the two rooms in separate zones while
+                // still diffusing gas across itself.
+                commands.entity(tile_entity).insert(Door);
+            }
+            if is_center {
+                center_tile = Some(tile_entity);
+            }
+
             // Spawn flow arrow as a child entity
             commands.spawn((
                 FlowArrow { parent_tile: tile_entity },
@@ -78,8 +102,25 @@ fn setup(mut commands: Commands) {
         }
     }
     
+    // Drop a life-support occupant on the air-filled center tile. Its marker
+    // sprite is tinted by breathability, turning the grid into a playable
+    // depressurization/scrubber scenario: seal or breach the room and watch the
+    // occupant's air go bad.
+    if let Some(tile) = center_tile {
+        commands.spawn((
+            Breather::new(tile),
+            OccupantMarker,
+            Sprite {
+                color: Color::srgb(0.0, 1.0, 0.0),
+                custom_size: Some(Vec2::new(10.0, 10.0)),
+                ..default()
+            },
+            Transform::from_xyz(0.0, 0.0, 2.0),
+        ));
+    }
+
     println!("Atmospheric simulation initialized!");
-    println!("Created 25x25 grid with air in center, surrounded by walls");
+    println!("Created 25x25 grid with two air rooms joined by a door, surrounded by walls");
     println!("Click on tiles to toggle walls");
     println!("\nColor legend:");
     println!("  Black: Deep vacuum");
@@ -131,6 +172,48 @@ fn visualize_flow_arrows(
     }
 }
 
+/// System to toggle between the pressure and temperature visualizations (T key).
+fn toggle_visualization_mode(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut mode: ResMut<VisualizationMode>,
+) {
+    if keys.just_pressed(KeyCode::KeyT) {
+        *mode = match *mode {
+            VisualizationMode::Pressure => VisualizationMode::Temperature,
+            VisualizationMode::Temperature => VisualizationMode::Pressure,
+        };
+        println!("Visualization mode: {:?}", *mode);
+    }
+}
+
+/// System to tint the occupant marker by its breathability, highlighting when
+/// the air has turned unbreathable, and to report health transitions.
+fn update_occupant_marker(
+    mut markers: Query<(&mut Sprite, &mut Transform), With<OccupantMarker>>,
+    breathers: Query<&Breather>,
+    tiles: Query<&Transform, (With<TilePosition>, Without<OccupantMarker>)>,
+) {
+    // The demo has a single occupant; pair it with the single marker.
+    let (Ok((mut sprite, mut transform)), Some(breather)) =
+        (markers.get_single_mut(), breathers.iter().next())
+    else {
+        return;
+    };
+
+    // Follow the occupied tile.
+    if let Ok(tile_transform) = tiles.get(breather.tile) {
+        transform.translation = tile_transform.translation;
+        transform.translation.z = 2.0;
+    }
+
+    sprite.color = match breather.state {
+        BreathState::Nominal => Color::srgb(0.0, 1.0, 0.0),
+        BreathState::Uncomfortable => Color::srgb(0.8, 0.8, 0.0),
+        BreathState::Unhealthy => Color::srgb(1.0, 0.5, 0.0),
+        BreathState::Suffocating | BreathState::Dangerous => Color::srgb(1.0, 0.0, 0.0),
+    };
+}
+
 /// System to handle mouse clicks on tiles to toggle walls
 fn handle_tile_click(
     mouse_button: Res<ButtonInput<MouseButton>>,
@@ -139,7 +222,6 @@ fn handle_tile_click(
     tiles: Query<(Entity, &Transform, &TilePosition, Option<&Wall>)>,
     mut commands: Commands,
     mut tile_atmosphere: Query<&mut TileAtmosphere>,
-    all_tiles: Query<(Entity, &TilePosition)>,
 ) {
     if !mouse_button.just_pressed(MouseButton::Left) {
         return;
@@ -174,28 +256,16 @@ fn handle_tile_click(
                     continue;
                 }
                 
-                // Toggle wall
+                // Toggle wall. update_wall_connections observes the Wall
+                // add/remove and seeds the affected tiles into the work queue.
                 if wall.is_some() {
                     // Remove wall
                     commands.entity(entity).remove::<Wall>();
                     println!("Removed wall at ({}, {})", pos.x, pos.y);
-                    
-                    // Mark tile and all neighbors as active to trigger gas flow
-                    commands.entity(entity).insert(AtmosphereActive);
-                    
-                    // Activate all neighboring tiles
-                    let neighbor_positions = pos.neighbors();
-                    for neighbor_pos in neighbor_positions.iter() {
-                        for (neighbor_entity, neighbor_tile_pos) in all_tiles.iter() {
-                            if neighbor_tile_pos == neighbor_pos {
-                                commands.entity(neighbor_entity).insert(AtmosphereActive);
-                            }
-                        }
-                    }
                 } else {
                     // Add wall
                     commands.entity(entity).insert(Wall);
-                    
+
                     // Clear atmosphere when wall is added
                     if let Ok(mut atmos) = tile_atmosphere.get_mut(entity) {
                         atmos.mixture = atmosphere::gas::GasMixture::new(
@@ -204,9 +274,6 @@ fn handle_tile_click(
                         );
                     }
                     println!("Added wall at ({}, {})", pos.x, pos.y);
-                    
-                    // Mark tile as active to trigger recalculation
-                    commands.entity(entity).insert(AtmosphereActive);
                 }
                 
                 break;